@@ -87,29 +87,18 @@ pub fn easy_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 		Value::Float => quote! {Number(crate::units::F32),},
 	});
 
-	let display_lines = input.values.iter().map(|value| match value {
+	let value_lines = input.values.iter().map(|value| match value {
 		Value::EnumVariant(value) => {
 			let value_camel = proc_macro2::Ident::new(&value.0.to_camel_case(), Span::call_site());
-			let css_string = format!("{}:{};", input.property.0, value.0);
+			let css_string = &value.0;
 			quote! {Self::#value_camel => write!(f, #css_string),}
 		},
-		Value::Unit => {
-			let css_format_string = format!("{}:{{}};", input.property.0);
-			quote! {Self::Some(x) => write!(f, #css_format_string, x),}
-		},
-		Value::String => {
-			let css_format_string = format!(r#"{}:"{{}}";"#, input.property.0);
-			quote! {Self::String(x) => write!(f, #css_format_string, x),}
-		},
-		Value::Raw => {
-			let css_format_string = format!("{}:{{}};", input.property.0);
-			quote! {Self::Raw(x) => write!(f, #css_format_string, x),}
-		},
-		Value::Number | Value::Float => {
-			let css_format_string = format!("{}:{{}};", input.property.0);
-			quote! {Self::Number(x) => write!(f, #css_format_string, x),}
-		},
+		Value::Unit => quote! {Self::Some(x) => write!(f, "{}", x),},
+		Value::String => quote! {Self::String(x) => write!(f, r#""{}""#, x),},
+		Value::Raw => quote! {Self::Raw(x) => write!(f, "{}", x),},
+		Value::Number | Value::Float => quote! {Self::Number(x) => write!(f, "{}", x),},
 	});
+	let css_prefix = format!("{}:", input.property.0);
 
 	let macro_values = input.values.iter().map(|value| match value {
 		Value::EnumVariant(value) => {
@@ -130,14 +119,23 @@ pub fn easy_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 			#(#enum_members)*
 		}
 
-		impl ::std::fmt::Display for #property_camel {
-			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+		impl #property_camel {
+			/// Write just the value, without the property name, e.g. for use in shorthands
+			pub fn fmt_value(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
 				match self {
-					#(#display_lines)*
+					#(#value_lines)*
 				}
 			}
 		}
 
+		impl ::std::fmt::Display for #property_camel {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+				f.write_str(#css_prefix)?;
+				self.fmt_value(f)?;
+				f.write_str(";")
+			}
+		}
+
 		#[macro_export]
 		macro_rules! #property_snek {
 			#(#macro_values)*
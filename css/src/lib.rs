@@ -8,6 +8,7 @@ pub mod color;
 pub mod font_face;
 pub mod media;
 pub mod append_property;
+pub mod prefixer;
 
 #[doc(hidden)]
 pub use paste;
@@ -18,6 +19,8 @@ pub use hobo_css_macros as css_macros;
 pub use color::Color;
 pub use units::F32;
 pub use append_property::AppendProperty;
+pub use prefixer::Prefixer;
+pub use shortcuts::BoxLayout;
 
 #[extend::ext(pub)]
 impl F32 {
@@ -0,0 +1,300 @@
+//! vendor prefixing driven by a browser support table
+//!
+//! properties are written unprefixed, a `Prefixer` then adds whatever prefixed variants the targeted browsers need
+
+use crate::{selector::{PseudoElement, SelectorComponent}, Property, Rule, Style, StyleRule};
+
+/// `(major, minor)`
+pub type Version = (u16, u16);
+
+/// For features that still require a prefix in the latest version of a browser
+pub const NEVER: Version = (u16::MAX, 0);
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Browser {
+	Chrome,
+	Edge,
+	Firefox,
+	Safari,
+	IosSafari,
+	Samsung,
+	Ie,
+}
+
+impl Browser {
+	pub const ALL: [Browser; 7] = [Self::Chrome, Self::Edge, Self::Firefox, Self::Safari, Self::IosSafari, Self::Samsung, Self::Ie];
+}
+
+/// A browser that needs `prefixed` for versions older than `until`
+///
+/// `prefixed` is the prefix itself for properties (`-webkit-`),
+/// the whole replacement value for values (`-webkit-box`)
+/// and the whole replacement pseudo-element for pseudo-elements (`::-moz-selection`)
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Support {
+	pub browser: Browser,
+	pub prefixed: &'static str,
+	pub until: Version,
+}
+
+pub struct PropertyEntry {
+	pub property: &'static str,
+	pub support: &'static [Support],
+}
+
+pub struct ValueEntry {
+	pub property: &'static str,
+	pub value: &'static str,
+	pub support: &'static [Support],
+}
+
+pub struct PseudoElementEntry {
+	pub element: PseudoElement,
+	pub support: &'static [Support],
+}
+
+macro_rules! support {
+	($($browser:ident $prefixed:literal < $until:expr),*$(,)?) => {
+		&[$(Support { browser: Browser::$browser, prefixed: $prefixed, until: $until }),*]
+	};
+}
+
+macro_rules! prefix_table {
+	(
+		properties => ($($property:literal => [$($p_support:tt)*]),*$(,)?),
+		values => ($($v_property:literal : $value:literal => [$($v_support:tt)*]),*$(,)?),
+		pseudo_elements => ($($element:ident => [$($e_support:tt)*]),*$(,)?),
+	) => {
+		pub static PROPERTIES: &[PropertyEntry] = &[$(PropertyEntry { property: $property, support: support!($($p_support)*) }),*];
+		pub static VALUES: &[ValueEntry] = &[$(ValueEntry { property: $v_property, value: $value, support: support!($($v_support)*) }),*];
+		pub static PSEUDO_ELEMENTS: &[PseudoElementEntry] = &[$(PseudoElementEntry { element: PseudoElement::$element, support: support!($($e_support)*) }),*];
+	};
+}
+
+// older variants go first, so that the unprefixed declaration (which is always last) wins where supported
+#[rustfmt::skip]
+prefix_table! {
+	properties => (
+		"appearance"           => [Chrome "-webkit-" < (84, 0), Edge "-webkit-" < (84, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (14, 0), Firefox "-moz-" < (80, 0)],
+		"backdrop-filter"      => [Safari "-webkit-" < (18, 0), IosSafari "-webkit-" < (18, 0)],
+		"backface-visibility"  => [Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4)],
+		"box-decoration-break" => [Chrome "-webkit-" < (130, 0), Edge "-webkit-" < (130, 0), Safari "-webkit-" < NEVER, IosSafari "-webkit-" < NEVER, Samsung "-webkit-" < NEVER],
+		"clip-path"            => [Chrome "-webkit-" < (55, 0), Safari "-webkit-" < (13, 1), IosSafari "-webkit-" < (13, 0), Samsung "-webkit-" < (6, 0)],
+		"hyphens"              => [Safari "-webkit-" < (17, 0), IosSafari "-webkit-" < (17, 0), Ie "-ms-" < NEVER],
+		"mask"                 => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"mask-clip"            => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"mask-image"           => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"mask-origin"          => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"mask-position"        => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"mask-repeat"          => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"mask-size"            => [Chrome "-webkit-" < (120, 0), Edge "-webkit-" < (120, 0), Safari "-webkit-" < (15, 4), IosSafari "-webkit-" < (15, 4), Samsung "-webkit-" < (25, 0)],
+		"tab-size"             => [Firefox "-moz-" < (91, 0)],
+		"text-size-adjust"     => [IosSafari "-webkit-" < NEVER, Firefox "-moz-" < NEVER],
+		"user-select"          => [Chrome "-webkit-" < (54, 0), Safari "-webkit-" < NEVER, IosSafari "-webkit-" < NEVER, Samsung "-webkit-" < (6, 0), Firefox "-moz-" < (69, 0), Edge "-ms-" < (79, 0), Ie "-ms-" < NEVER],
+	),
+	values => (
+		"display"  : "flex"        => [Safari "-webkit-box" < (6, 1), IosSafari "-webkit-box" < (7, 0), Safari "-webkit-flex" < (9, 0), IosSafari "-webkit-flex" < (9, 0), Ie "-ms-flexbox" < (11, 0)],
+		"display"  : "inline-flex" => [Safari "-webkit-inline-box" < (6, 1), IosSafari "-webkit-inline-box" < (7, 0), Safari "-webkit-inline-flex" < (9, 0), IosSafari "-webkit-inline-flex" < (9, 0), Ie "-ms-inline-flexbox" < (11, 0)],
+		"display"  : "grid"        => [Edge "-ms-grid" < (16, 0), Ie "-ms-grid" < NEVER],
+		"position" : "sticky"      => [Safari "-webkit-sticky" < (13, 0), IosSafari "-webkit-sticky" < (13, 0)],
+		"cursor"   : "grab"        => [Chrome "-webkit-grab" < (68, 0), Safari "-webkit-grab" < (11, 0), Firefox "-moz-grab" < (27, 0)],
+		"cursor"   : "grabbing"    => [Chrome "-webkit-grabbing" < (68, 0), Safari "-webkit-grabbing" < (11, 0), Firefox "-moz-grabbing" < (27, 0)],
+		"cursor"   : "zoom-in"     => [Chrome "-webkit-zoom-in" < (37, 0), Safari "-webkit-zoom-in" < (11, 0), Firefox "-moz-zoom-in" < (24, 0)],
+		"cursor"   : "zoom-out"    => [Chrome "-webkit-zoom-out" < (37, 0), Safari "-webkit-zoom-out" < (11, 0), Firefox "-moz-zoom-out" < (24, 0)],
+	),
+	pseudo_elements => (
+		selection   => [Firefox "::-moz-selection" < (62, 0)],
+		placeholder => [Chrome "::-webkit-input-placeholder" < (57, 0), Safari "::-webkit-input-placeholder" < (10, 1), IosSafari "::-webkit-input-placeholder" < (10, 3), Samsung "::-webkit-input-placeholder" < (7, 0), Firefox "::-moz-placeholder" < (51, 0), Edge "::-ms-input-placeholder" < (79, 0), Ie ":-ms-input-placeholder" < NEVER],
+	),
+}
+
+/// Adds vendor prefixed variants of properties, values and pseudo-elements for the targeted browsers
+///
+/// ```
+/// # use hobo_css::prefixer::{Browser, Prefixer};
+/// let prefixer = Prefixer::new().target(Browser::Safari, (12, 0));
+/// let properties = prefixer.prefix_properties(&[hobo_css::user_select!(none)]);
+/// assert_eq!(properties.iter().map(ToString::to_string).collect::<String>(), "-webkit-user-select:none;user-select:none;");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Prefixer {
+	targets: Vec<(Browser, Version)>,
+}
+
+/// Roughly the browsers in use at the time of writing, also covers all prefixes that hobo used to hardcode
+impl Default for Prefixer {
+	fn default() -> Self {
+		Self::new()
+			.target(Browser::Chrome, (80, 0))
+			.target(Browser::Edge, (80, 0))
+			.target(Browser::Firefox, (72, 0))
+			.target(Browser::Safari, (12, 0))
+			.target(Browser::IosSafari, (12, 0))
+			.target(Browser::Samsung, (12, 0))
+	}
+}
+
+impl Prefixer {
+	/// A prefixer with no targets, which leaves everything as is
+	pub fn new() -> Self { Self { targets: Vec::new() } }
+
+	/// Emit every prefix in the table regardless of browser versions
+	pub fn all() -> Self { Self { targets: Browser::ALL.iter().map(|&browser| (browser, (0, 0))).collect() } }
+
+	/// Support `browser` from `version` onwards
+	pub fn target(mut self, browser: Browser, version: Version) -> Self {
+		if let Some(target) = self.targets.iter_mut().find(|(x, _)| *x == browser) {
+			target.1 = version;
+		} else {
+			self.targets.push((browser, version));
+		}
+		self
+	}
+
+	fn needed<'a>(&'a self, support: &'a [Support]) -> impl Iterator<Item = &'static str> + 'a {
+		let mut res = Vec::new();
+		for x in support {
+			let is_needed = self.targets.iter().any(|&(browser, version)| browser == x.browser && version < x.until);
+			if is_needed && !res.contains(&x.prefixed) { res.push(x.prefixed); }
+		}
+		res.into_iter()
+	}
+
+	pub fn prefix_properties(&self, properties: &[Property]) -> Vec<Property> {
+		let mut res = Vec::with_capacity(properties.len());
+		for property in properties {
+			let rendered = property.to_string();
+			for (name, value) in declarations(&rendered) {
+				if name.starts_with('-') { continue; }
+
+				for entry in PROPERTIES.iter().filter(|entry| entry.property == name) {
+					for prefix in self.needed(entry.support) {
+						res.push(Property::Raw(format!("{}{}:{};", prefix, name, value)));
+					}
+				}
+
+				for entry in VALUES.iter().filter(|entry| entry.property == name && entry.value == value) {
+					for prefixed in self.needed(entry.support) {
+						res.push(Property::Raw(format!("{}:{};", name, prefixed)));
+					}
+				}
+			}
+			res.push(property.clone());
+		}
+		res
+	}
+
+	pub fn prefix_style(&self, style: &Style) -> Style {
+		let mut res = Vec::with_capacity(style.0.len());
+		for rule in &style.0 {
+			match rule {
+				Rule::Style(StyleRule(selector, properties)) => {
+					let properties = self.prefix_properties(properties);
+
+					// browsers drop the whole rule if they don't understand a pseudo-element, so every prefixed variant gets its own rule
+					for entry in PSEUDO_ELEMENTS.iter().filter(|entry| selector.0.contains(&SelectorComponent::PseudoElement(entry.element))) {
+						for prefixed in self.needed(entry.support) {
+							let mut selector = selector.clone();
+							for component in selector.0.iter_mut().filter(|x| **x == SelectorComponent::PseudoElement(entry.element)) {
+								*component = SelectorComponent::Raw(prefixed.into());
+							}
+							res.push(Rule::Style(StyleRule(selector, properties.clone())));
+						}
+					}
+
+					res.push(Rule::Style(StyleRule(selector.clone(), properties)));
+				},
+				Rule::Media(selector, style) => res.push(Rule::Media(selector.clone(), self.prefix_style(style))),
+				Rule::FontFace(_) => res.push(rule.clone()),
+			}
+		}
+		Style(res)
+	}
+}
+
+// split a rendered list of declarations into (name, value) pairs
+// semicolons inside of quotes or parens (e.g. in `url()`) don't separate declarations
+fn declarations(s: &str) -> Vec<(&str, &str)> {
+	let mut res = Vec::new();
+	let mut depth = 0usize;
+	let mut quote = None;
+	let mut start = 0;
+	for (i, c) in s.char_indices() {
+		match (c, quote) {
+			('"', None) | ('\'', None) => quote = Some(c),
+			(c, Some(q)) if c == q => quote = None,
+			(_, Some(_)) => {},
+			('(', None) => depth += 1,
+			(')', None) => depth = depth.saturating_sub(1),
+			(';', None) if depth == 0 => {
+				res.extend(declaration(&s[start..i]));
+				start = i + 1;
+			},
+			_ => {},
+		}
+	}
+	res.extend(declaration(&s[start..]));
+	res
+}
+
+fn declaration(s: &str) -> Option<(&str, &str)> {
+	let idx = s.find(':')?;
+	Some((s[..idx].trim(), s[idx + 1..].trim()))
+}
+
+#[test]
+fn prefix_property_names() {
+	let prefixer = Prefixer::all();
+	assert_eq!(
+		prefixer.prefix_properties(&[appearance!(none)]),
+		vec![
+			Property::Raw("-webkit-appearance:none;".into()),
+			Property::Raw("-moz-appearance:none;".into()),
+			appearance!(none),
+		],
+	);
+	assert_eq!(
+		prefixer.prefix_properties(&[Property::Raw("backdrop-filter:blur(2px);mask-image:url(\"a;b.svg\");".into())]),
+		vec![
+			Property::Raw("-webkit-backdrop-filter:blur(2px);".into()),
+			Property::Raw("-webkit-mask-image:url(\"a;b.svg\");".into()),
+			Property::Raw("backdrop-filter:blur(2px);mask-image:url(\"a;b.svg\");".into()),
+		],
+	);
+}
+
+#[test]
+fn prefix_values() {
+	let prefixer = Prefixer::new().target(Browser::Safari, (6, 0));
+	assert_eq!(
+		prefixer.prefix_properties(&[display!(flex), position!(sticky)]).iter().map(ToString::to_string).collect::<String>(),
+		"display:-webkit-box;display:-webkit-flex;display:flex;position:-webkit-sticky;position:sticky;",
+	);
+}
+
+#[test]
+fn prefix_targets() {
+	assert_eq!(Prefixer::new().prefix_properties(&[user_select!(none)]), vec![user_select!(none)]);
+	assert_eq!(Prefixer::new().target(Browser::Firefox, (70, 0)).prefix_properties(&[user_select!(none)]), vec![user_select!(none)]);
+	assert_eq!(
+		Prefixer::new().target(Browser::Firefox, (60, 0)).prefix_properties(&[user_select!(none)]),
+		vec![Property::Raw("-moz-user-select:none;".into()), user_select!(none)],
+	);
+	assert_eq!(
+		Prefixer::default().prefix_properties(&[Property::ClipPath(crate::ClipPath::None)]),
+		vec![Property::Raw("-webkit-clip-path:none;".into()), Property::ClipPath(crate::ClipPath::None)],
+	);
+}
+
+#[test]
+fn prefix_pseudo_elements() {
+	let prefixer = Prefixer::new().target(Browser::Firefox, (60, 0));
+	assert_eq!(
+		prefixer.prefix_style(&crate::style!(div::selection { color!(0xFF_00_00_FF) })).to_string(),
+		"div::-moz-selection{color:#ff0000ff;}div::selection{color:#ff0000ff;}",
+	);
+	assert_eq!(
+		prefixer.prefix_style(&crate::style!(@media All { div { user_select!(none) } })).to_string(),
+		"@media all{div{-moz-user-select:none;user-select:none;}}",
+	);
+}
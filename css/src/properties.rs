@@ -59,6 +59,10 @@ pub enum UnitValue {
 	Unset,
 }
 
+impl From<Unit> for UnitValue {
+	fn from(x: Unit) -> Self { Self::Unit(x) }
+}
+
 #[rustfmt::skip]
 impl std::fmt::Display for UnitValue {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -71,6 +75,17 @@ impl std::fmt::Display for UnitValue {
 	}
 }
 
+// renders a closure, handy to get at the value of a property without its name
+pub(crate) fn display_fn(f: impl Fn(&mut std::fmt::Formatter<'_>) -> std::fmt::Result) -> impl std::fmt::Display {
+	struct DisplayFn<F>(F);
+
+	impl<F: Fn(&mut std::fmt::Formatter<'_>) -> std::fmt::Result> std::fmt::Display for DisplayFn<F> {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { (self.0)(f) }
+	}
+
+	DisplayFn(f)
+}
+
 /*
 pub enum RadialGradientShape {
 	Circle,
@@ -276,6 +291,18 @@ generate_properties! {
 		Appearance,
 		Float,
 		Clear,
+		Border,
+		Outline,
+		Font,
+		Background,
+		GridTemplateAreas,
+		GridArea,
+		GridTemplateShorthand,
+		Grid,
+		PlaceItems,
+		PlaceContent,
+		PlaceSelf,
+		Gap,
 	),
 	// different properties that take the same argument
 	named => (
@@ -283,11 +310,19 @@ generate_properties! {
 		"margin-right" => MarginRight(Margin),
 		"margin-top" => MarginTop(Margin),
 		"margin-bottom" => MarginBottom(Margin),
+		"margin-inline-start" => MarginInlineStart(Margin),
+		"margin-inline-end" => MarginInlineEnd(Margin),
+		"margin-block-start" => MarginBlockStart(Margin),
+		"margin-block-end" => MarginBlockEnd(Margin),
 
 		"padding-left" => PaddingLeft(UnitValue),
 		"padding-right" => PaddingRight(UnitValue),
 		"padding-top" => PaddingTop(UnitValue),
 		"padding-bottom" => PaddingBottom(UnitValue),
+		"padding-inline-start" => PaddingInlineStart(UnitValue),
+		"padding-inline-end" => PaddingInlineEnd(UnitValue),
+		"padding-block-start" => PaddingBlockStart(UnitValue),
+		"padding-block-end" => PaddingBlockEnd(UnitValue),
 
 		"width" => Width(Dimension),
 		"height" => Height(Dimension),
@@ -295,31 +330,57 @@ generate_properties! {
 		"max-width" => MaxWidth(DimensionExtremity),
 		"min-height" => MinHeight(DimensionExtremity),
 		"max-height" => MaxHeight(DimensionExtremity),
+		"inline-size" => InlineSize(Dimension),
+		"block-size" => BlockSize(Dimension),
+		"min-inline-size" => MinInlineSize(DimensionExtremity),
+		"max-inline-size" => MaxInlineSize(DimensionExtremity),
+		"min-block-size" => MinBlockSize(DimensionExtremity),
+		"max-block-size" => MaxBlockSize(DimensionExtremity),
 
 		"top" => Top(Dimension),
 		"right" => Right(Dimension),
 		"left" => Left(Dimension),
 		"bottom" => Bottom(Dimension),
+		"inset-inline-start" => InsetInlineStart(Dimension),
+		"inset-inline-end" => InsetInlineEnd(Dimension),
+		"inset-block-start" => InsetBlockStart(Dimension),
+		"inset-block-end" => InsetBlockEnd(Dimension),
 
 		"border-left-color" => BorderLeftColor(ColorValue),
 		"border-right-color" => BorderRightColor(ColorValue),
 		"border-top-color" => BorderTopColor(ColorValue),
 		"border-bottom-color" => BorderBottomColor(ColorValue),
+		"border-inline-start-color" => BorderInlineStartColor(ColorValue),
+		"border-inline-end-color" => BorderInlineEndColor(ColorValue),
+		"border-block-start-color" => BorderBlockStartColor(ColorValue),
+		"border-block-end-color" => BorderBlockEndColor(ColorValue),
 
 		"border-left-style" => BorderLeftStyle(BorderStyle),
 		"border-right-style" => BorderRightStyle(BorderStyle),
 		"border-top-style" => BorderTopStyle(BorderStyle),
 		"border-bottom-style" => BorderBottomStyle(BorderStyle),
+		"border-inline-start-style" => BorderInlineStartStyle(BorderStyle),
+		"border-inline-end-style" => BorderInlineEndStyle(BorderStyle),
+		"border-block-start-style" => BorderBlockStartStyle(BorderStyle),
+		"border-block-end-style" => BorderBlockEndStyle(BorderStyle),
 
 		"border-left-width" => BorderLeftWidth(BorderWidth),
 		"border-right-width" => BorderRightWidth(BorderWidth),
 		"border-top-width" => BorderTopWidth(BorderWidth),
 		"border-bottom-width" => BorderBottomWidth(BorderWidth),
+		"border-inline-start-width" => BorderInlineStartWidth(BorderWidth),
+		"border-inline-end-width" => BorderInlineEndWidth(BorderWidth),
+		"border-block-start-width" => BorderBlockStartWidth(BorderWidth),
+		"border-block-end-width" => BorderBlockEndWidth(BorderWidth),
 
 		"border-top-left-radius" => BorderTopLeftRadius(UnitValue),
 		"border-top-right-radius" => BorderTopRightRadius(UnitValue),
 		"border-bottom-left-radius" => BorderBottomLeftRadius(UnitValue),
 		"border-bottom-right-radius" => BorderBottomRightRadius(UnitValue),
+		"border-start-start-radius" => BorderStartStartRadius(UnitValue),
+		"border-start-end-radius" => BorderStartEndRadius(UnitValue),
+		"border-end-start-radius" => BorderEndStartRadius(UnitValue),
+		"border-end-end-radius" => BorderEndEndRadius(UnitValue),
 
 		"background-color" => BackgroundColor(ColorValue),
 		"color" => Color(ColorValue),
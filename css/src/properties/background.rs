@@ -37,3 +37,179 @@ impl std::fmt::Display for BackgroundImage {
 		}
 	}
 }
+
+/// One layer of the `background` shorthand, left out components are reset to their initial values
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct BackgroundLayer {
+	pub image: Option<crate::Image>,
+	pub position: Option<(Unit, Unit)>,
+	pub size: Option<BackgroundSize>,
+	pub repeat: Option<BackgroundRepeat>,
+	pub attachment: Option<BackgroundAttachment>,
+	pub origin: Option<BackgroundOrigin>,
+}
+
+impl std::fmt::Display for BackgroundLayer {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.image {
+			Some(image) => image.fmt(f)?,
+			None => "none".fmt(f)?,
+		}
+		if self.position.is_some() || self.size.is_some() {
+			let (x, y) = self.position.unwrap_or((Unit::Zero, Unit::Zero));
+			write!(f, " {} {}", x, y)?;
+		}
+		if let Some(x) = &self.size { " / ".fmt(f)?; x.fmt_value(f)?; }
+		if let Some(x) = &self.repeat { " ".fmt(f)?; x.fmt_value(f)?; }
+		if let Some(x) = &self.attachment { " ".fmt(f)?; x.fmt_value(f)?; }
+		if let Some(x) = &self.origin { " ".fmt(f)?; x.fmt_value(f)?; }
+		Ok(())
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Background {
+	None,
+	Initial,
+	Inherit,
+	Unset,
+	Some {
+		layers: Vec<BackgroundLayer>,
+		color: Option<crate::Color>,
+	},
+}
+
+impl std::fmt::Display for Background {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::None    => "background:none;".fmt(f),
+			Self::Initial => "background:initial;".fmt(f),
+			Self::Inherit => "background:inherit;".fmt(f),
+			Self::Unset   => "background:unset;".fmt(f),
+			Self::Some { layers, color } => {
+				"background:".fmt(f)?;
+				if let Some((first, rest)) = layers.split_first() {
+					write!(f, "{}", first)?;
+					for layer in rest {
+						write!(f, ",{}", layer)?;
+					}
+					if let Some(color) = color { write!(f, " {}", color)?; }
+				} else if let Some(color) = color {
+					write!(f, "{}", color)?;
+				} else {
+					"none".fmt(f)?;
+				}
+				";".fmt(f)
+			},
+		}
+	}
+}
+
+impl Background {
+	/// The long-hand properties equivalent to this shorthand
+	///
+	/// Long-hands that can't hold more than one value are emitted as `Property::Raw` comma separated lists when there are several layers
+	pub fn expand(&self) -> Vec<crate::Property> {
+		use crate::Property;
+
+		macro_rules! keyword {
+			($kw:ident) => {vec![
+				Property::BackgroundImage(BackgroundImage::$kw),
+				Property::BackgroundPositionX(crate::UnitValue::$kw),
+				Property::BackgroundPositionY(crate::UnitValue::$kw),
+				Property::BackgroundSize(BackgroundSize::$kw),
+				Property::BackgroundRepeat(BackgroundRepeat::$kw),
+				Property::BackgroundAttachment(BackgroundAttachment::$kw),
+				Property::BackgroundOrigin(BackgroundOrigin::$kw),
+				Property::BackgroundColor(crate::ColorValue::$kw),
+			]};
+		}
+
+		let (layers, color) = match self {
+			Self::Initial => return keyword!(Initial),
+			Self::Inherit => return keyword!(Inherit),
+			Self::Unset   => return keyword!(Unset),
+			Self::None => (&[][..], None),
+			Self::Some { layers, color } => (&layers[..], *color),
+		};
+		let default_layer = [BackgroundLayer::default()];
+		let layers = if layers.is_empty() { &default_layer[..] } else { layers };
+
+		let color = Property::BackgroundColor(color.map_or(crate::ColorValue::Initial, crate::ColorValue::Rgba));
+		if let [layer] = layers {
+			let (x, y) = match layer.position {
+				Some((x, y)) => (crate::UnitValue::Unit(x), crate::UnitValue::Unit(y)),
+				None => (crate::UnitValue::Initial, crate::UnitValue::Initial),
+			};
+			return vec![
+				Property::BackgroundImage(layer.image.clone().map_or(BackgroundImage::None, |image| BackgroundImage::Some(vec![image]))),
+				Property::BackgroundPositionX(x),
+				Property::BackgroundPositionY(y),
+				Property::BackgroundSize(layer.size.clone().unwrap_or(BackgroundSize::Initial)),
+				Property::BackgroundRepeat(layer.repeat.clone().unwrap_or(BackgroundRepeat::Initial)),
+				Property::BackgroundAttachment(layer.attachment.clone().unwrap_or(BackgroundAttachment::Initial)),
+				Property::BackgroundOrigin(layer.origin.clone().unwrap_or(BackgroundOrigin::Initial)),
+				color,
+			];
+		}
+
+		fn list(name: &str, layers: &[BackgroundLayer], f: impl Fn(&BackgroundLayer) -> String) -> Property {
+			Property::Raw(format!("{}:{};", name, layers.iter().map(f).collect::<Vec<_>>().join(",")))
+		}
+
+		vec![
+			list("background-image", layers, |l| l.image.as_ref().map_or("none".to_owned(), ToString::to_string)),
+			list("background-position-x", layers, |l| l.position.map_or("0%".to_owned(), |(x, _)| x.to_string())),
+			list("background-position-y", layers, |l| l.position.map_or("0%".to_owned(), |(_, y)| y.to_string())),
+			list("background-size", layers, |l| l.size.as_ref().map_or("auto".to_owned(), |x| crate::display_fn(|f| x.fmt_value(f)).to_string())),
+			list("background-repeat", layers, |l| l.repeat.as_ref().map_or("repeat".to_owned(), |x| crate::display_fn(|f| x.fmt_value(f)).to_string())),
+			list("background-attachment", layers, |l| l.attachment.as_ref().map_or("scroll".to_owned(), |x| crate::display_fn(|f| x.fmt_value(f)).to_string())),
+			list("background-origin", layers, |l| l.origin.as_ref().map_or("padding-box".to_owned(), |x| crate::display_fn(|f| x.fmt_value(f)).to_string())),
+			color,
+		]
+	}
+}
+
+#[rustfmt::skip]
+#[macro_export]
+macro_rules! background {
+	(none)    => {$crate::Property::Background($crate::Background::None)};
+	(initial) => {$crate::Property::Background($crate::Background::Initial)};
+	(inherit) => {$crate::Property::Background($crate::Background::Inherit)};
+	(unset)   => {$crate::Property::Background($crate::Background::Unset)};
+	($($layer:expr),+ ; $color:expr) => {$crate::Property::Background($crate::Background::Some { layers: vec![$($layer),+], color: Some($color.into()) })};
+	($($layer:expr),+)               => {$crate::Property::Background($crate::Background::Some { layers: vec![$($layer),+], color: None })};
+}
+
+#[test]
+fn background_shorthand() {
+	let top = BackgroundLayer { image: Some(crate::Image::Url("a.png".into())), repeat: Some(BackgroundRepeat::NoRepeat), ..Default::default() };
+	let bottom = BackgroundLayer {
+		image: Some(crate::Image::Url("b.png".into())),
+		position: Some((Unit::Percent(F32::new_unwrap(50.)), Unit::Zero)),
+		size: Some(BackgroundSize::Cover),
+		..Default::default()
+	};
+	assert_eq!(background!(top.clone()).to_string(), r#"background:url("a.png") no-repeat;"#);
+	assert_eq!(
+		background!(top.clone(), bottom.clone(); 0xFF_FF_FF_FFu32).to_string(),
+		r#"background:url("a.png") no-repeat,url("b.png") 50% 0 / cover #ffffffff;"#,
+	);
+	assert_eq!(background!(none).to_string(), "background:none;");
+
+	assert_eq!(Background::Some { layers: vec![top.clone()], color: None }.expand(), vec![
+		crate::Property::BackgroundImage(BackgroundImage::Some(vec![crate::Image::Url("a.png".into())])),
+		background_position_x!(initial),
+		background_position_y!(initial),
+		background_size!(initial),
+		background_repeat!(no-repeat),
+		background_attachment!(initial),
+		background_origin!(initial),
+		background_color!(initial),
+	]);
+
+	let expanded = Background::Some { layers: vec![top, bottom], color: None }.expand();
+	assert_eq!(expanded[0], crate::Property::Raw(r#"background-image:url("a.png"),url("b.png");"#.into()));
+	assert_eq!(expanded[3], crate::Property::Raw("background-size:auto,cover;".into()));
+	assert_eq!(expanded[4], crate::Property::Raw("background-repeat:no-repeat,repeat;".into()));
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::{ColorValue, Property};
 
 css_macros::easy_enum! {border-collapse separate collapse}
 css_macros::easy_enum! {box-decoration-break slice clone}
@@ -148,6 +149,22 @@ macro_rules! __border_width {
 #[macro_export] macro_rules! border_right_width {($($tt:tt)+)  => {$crate::__border_width!(Right, $($tt)+)}}
 #[macro_export] macro_rules! border_top_width {($($tt:tt)+)    => {$crate::__border_width!(Top, $($tt)+)}}
 #[macro_export] macro_rules! border_bottom_width {($($tt:tt)+) => {$crate::__border_width!(Bottom, $($tt)+)}}
+#[macro_export] macro_rules! border_inline_start_width {($($tt:tt)+) => {$crate::__border_width!(InlineStart, $($tt)+)}}
+#[macro_export] macro_rules! border_inline_end_width {($($tt:tt)+)   => {$crate::__border_width!(InlineEnd, $($tt)+)}}
+#[macro_export] macro_rules! border_block_start_width {($($tt:tt)+)  => {$crate::__border_width!(BlockStart, $($tt)+)}}
+#[macro_export] macro_rules! border_block_end_width {($($tt:tt)+)    => {$crate::__border_width!(BlockEnd, $($tt)+)}}
+#[macro_export] macro_rules! border_inline_width {($($tt:tt)+) => {
+	vec![
+		$crate::border_inline_start_width!($($tt)+),
+		$crate::border_inline_end_width!($($tt)+),
+	]
+}}
+#[macro_export] macro_rules! border_block_width {($($tt:tt)+) => {
+	vec![
+		$crate::border_block_start_width!($($tt)+),
+		$crate::border_block_end_width!($($tt)+),
+	]
+}}
 #[macro_export] macro_rules! border_width {($($tt:tt)+) => {
 	vec![
 		$crate::border_left_width!($($tt)+),
@@ -180,6 +197,22 @@ macro_rules! __border_style {
 #[macro_export] macro_rules! border_right_style {($($tt:tt)+)  => {$crate::__border_style!(Right, $($tt)+)}}
 #[macro_export] macro_rules! border_top_style {($($tt:tt)+)    => {$crate::__border_style!(Top, $($tt)+)}}
 #[macro_export] macro_rules! border_bottom_style {($($tt:tt)+) => {$crate::__border_style!(Bottom, $($tt)+)}}
+#[macro_export] macro_rules! border_inline_start_style {($($tt:tt)+) => {$crate::__border_style!(InlineStart, $($tt)+)}}
+#[macro_export] macro_rules! border_inline_end_style {($($tt:tt)+)   => {$crate::__border_style!(InlineEnd, $($tt)+)}}
+#[macro_export] macro_rules! border_block_start_style {($($tt:tt)+)  => {$crate::__border_style!(BlockStart, $($tt)+)}}
+#[macro_export] macro_rules! border_block_end_style {($($tt:tt)+)    => {$crate::__border_style!(BlockEnd, $($tt)+)}}
+#[macro_export] macro_rules! border_inline_style {($($tt:tt)+) => {
+	vec![
+		$crate::border_inline_start_style!($($tt)+),
+		$crate::border_inline_end_style!($($tt)+),
+	]
+}}
+#[macro_export] macro_rules! border_block_style {($($tt:tt)+) => {
+	vec![
+		$crate::border_block_start_style!($($tt)+),
+		$crate::border_block_end_style!($($tt)+),
+	]
+}}
 #[macro_export] macro_rules! border_style {($($tt:tt)+) => {
 	vec![
 		$crate::border_left_style!($($tt)+),
@@ -202,6 +235,23 @@ css_macros::easy_color! {border_bottom_color}
 	]
 }}
 
+css_macros::easy_color! {border_inline_start_color}
+css_macros::easy_color! {border_inline_end_color}
+css_macros::easy_color! {border_block_start_color}
+css_macros::easy_color! {border_block_end_color}
+#[macro_export] macro_rules! border_inline_color {($($tt:tt)+) => {
+	vec![
+		$crate::border_inline_start_color!($($tt)+),
+		$crate::border_inline_end_color!($($tt)+),
+	]
+}}
+#[macro_export] macro_rules! border_block_color {($($tt:tt)+) => {
+	vec![
+		$crate::border_block_start_color!($($tt)+),
+		$crate::border_block_end_color!($($tt)+),
+	]
+}}
+
 css_macros::unit_value_macro! {border_top_left_radius BorderTopLeftRadius}
 css_macros::unit_value_macro! {border_top_right_radius BorderTopRightRadius}
 css_macros::unit_value_macro! {border_bottom_left_radius BorderBottomLeftRadius}
@@ -215,6 +265,174 @@ css_macros::unit_value_macro! {border_bottom_right_radius BorderBottomRightRadiu
 	]
 }}
 
+css_macros::unit_value_macro! {border_start_start_radius BorderStartStartRadius}
+css_macros::unit_value_macro! {border_start_end_radius BorderStartEndRadius}
+css_macros::unit_value_macro! {border_end_start_radius BorderEndStartRadius}
+css_macros::unit_value_macro! {border_end_end_radius BorderEndEndRadius}
+
+/// The components of the `border` and `outline` shorthands, left out components are reset to their initial values
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct BorderLine {
+	pub width: Option<BorderWidth>,
+	pub style: Option<BorderStyle>,
+	pub color: Option<crate::Color>,
+}
+
+impl std::fmt::Display for BorderLine {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let mut sep = "";
+		if let Some(width) = self.width { write!(f, "{}", width)?; sep = " "; }
+		if let Some(style) = self.style { write!(f, "{}{}", sep, style)?; sep = " "; }
+		if let Some(color) = self.color { write!(f, "{}{}", sep, color)?; sep = " "; }
+		if sep.is_empty() { "none".fmt(f)?; }
+		Ok(())
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Border {
+	Initial,
+	Inherit,
+	Unset,
+	Some(BorderLine),
+}
+
+impl std::fmt::Display for Border {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Initial => "border:initial;".fmt(f),
+			Self::Inherit => "border:inherit;".fmt(f),
+			Self::Unset   => "border:unset;".fmt(f),
+			Self::Some(line) => write!(f, "border:{};", line),
+		}
+	}
+}
+
+impl Border {
+	/// The long-hand properties equivalent to this shorthand
+	pub fn expand(&self) -> Vec<Property> {
+		let (width, style, color) = match self {
+			Self::Initial => (BorderWidth::Initial, BorderStyle::Initial, ColorValue::Initial),
+			Self::Inherit => (BorderWidth::Inherit, BorderStyle::Inherit, ColorValue::Inherit),
+			Self::Unset   => (BorderWidth::Unset, BorderStyle::Unset, ColorValue::Unset),
+			Self::Some(line) => (
+				line.width.unwrap_or(BorderWidth::Initial),
+				line.style.unwrap_or(BorderStyle::Initial),
+				line.color.map_or(ColorValue::Initial, ColorValue::Rgba),
+			),
+		};
+
+		vec![
+			Property::BorderLeftWidth(width), Property::BorderRightWidth(width),
+			Property::BorderTopWidth(width), Property::BorderBottomWidth(width),
+			Property::BorderLeftStyle(style), Property::BorderRightStyle(style),
+			Property::BorderTopStyle(style), Property::BorderBottomStyle(style),
+			Property::BorderLeftColor(color), Property::BorderRightColor(color),
+			Property::BorderTopColor(color), Property::BorderBottomColor(color),
+		]
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Outline {
+	Initial,
+	Inherit,
+	Unset,
+	Some(BorderLine),
+}
+
+impl std::fmt::Display for Outline {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Initial => "outline:initial;".fmt(f),
+			Self::Inherit => "outline:inherit;".fmt(f),
+			Self::Unset   => "outline:unset;".fmt(f),
+			Self::Some(line) => write!(f, "outline:{};", line),
+		}
+	}
+}
+
+impl Outline {
+	/// The long-hand properties equivalent to this shorthand
+	pub fn expand(&self) -> Vec<Property> {
+		let (width, style, color) = match self {
+			Self::Initial => (OutlineWidth::Initial, OutlineStyle::Initial, ColorValue::Initial),
+			Self::Inherit => (OutlineWidth::Inherit, OutlineStyle::Inherit, ColorValue::Inherit),
+			Self::Unset   => (OutlineWidth::Unset, OutlineStyle::Unset, ColorValue::Unset),
+			Self::Some(line) => (
+				match line.width {
+					Some(BorderWidth::Medium)  => OutlineWidth::Medium,
+					Some(BorderWidth::Thin)    => OutlineWidth::Thin,
+					Some(BorderWidth::Thick)   => OutlineWidth::Thick,
+					Some(BorderWidth::Some(x)) => OutlineWidth::Some(x),
+					Some(BorderWidth::Inherit) => OutlineWidth::Inherit,
+					Some(BorderWidth::Unset)   => OutlineWidth::Unset,
+					Some(BorderWidth::Initial) | None => OutlineWidth::Initial,
+				},
+				match line.style {
+					Some(BorderStyle::None)    => OutlineStyle::None,
+					Some(BorderStyle::Hidden)  => OutlineStyle::Hidden,
+					Some(BorderStyle::Dotted)  => OutlineStyle::Dotted,
+					Some(BorderStyle::Dashed)  => OutlineStyle::Dashed,
+					Some(BorderStyle::Solid)   => OutlineStyle::Solid,
+					Some(BorderStyle::Double)  => OutlineStyle::Double,
+					Some(BorderStyle::Groove)  => OutlineStyle::Groove,
+					Some(BorderStyle::Ridge)   => OutlineStyle::Ridge,
+					Some(BorderStyle::Inset)   => OutlineStyle::Inset,
+					Some(BorderStyle::Outset)  => OutlineStyle::Outset,
+					Some(BorderStyle::Inherit) => OutlineStyle::Inherit,
+					Some(BorderStyle::Unset)   => OutlineStyle::Unset,
+					Some(BorderStyle::Initial) | None => OutlineStyle::Initial,
+				},
+				line.color.map_or(ColorValue::Initial, ColorValue::Rgba),
+			),
+		};
+
+		vec![Property::OutlineWidth(width), Property::OutlineStyle(style), Property::OutlineColor(color)]
+	}
+}
+
+#[rustfmt::skip]
+#[macro_export]
+macro_rules! border {
+	(initial)    => {$crate::Property::Border($crate::Border::Initial)};
+	(inherit)    => {$crate::Property::Border($crate::Border::Inherit)};
+	(unset)      => {$crate::Property::Border($crate::Border::Unset)};
+	(none)       => {$crate::Property::Border($crate::Border::Some($crate::BorderLine { style: Some($crate::BorderStyle::None), ..Default::default() }))};
+	($line:expr) => {$crate::Property::Border($crate::Border::Some($line))};
+}
+
+#[rustfmt::skip]
+#[macro_export]
+macro_rules! outline {
+	(initial)    => {$crate::Property::Outline($crate::Outline::Initial)};
+	(inherit)    => {$crate::Property::Outline($crate::Outline::Inherit)};
+	(unset)      => {$crate::Property::Outline($crate::Outline::Unset)};
+	(none)       => {$crate::Property::Outline($crate::Outline::Some($crate::BorderLine { style: Some($crate::BorderStyle::None), ..Default::default() }))};
+	($line:expr) => {$crate::Property::Outline($crate::Outline::Some($line))};
+}
+
+#[test]
+fn border_shorthand() {
+	let line = BorderLine { width: Some(BorderWidth::Some(Unit::Px(F32::new_unwrap(2.)))), style: Some(BorderStyle::Solid), color: Some(crate::Color::from(0xFF_00_00_FFu32)) };
+	assert_eq!(border!(line).to_string(), "border:2px solid #ff0000ff;");
+	assert_eq!(border!(none).to_string(), "border:none;");
+	assert_eq!(border!(initial).to_string(), "border:initial;");
+	assert_eq!(outline!(line).to_string(), "outline:2px solid #ff0000ff;");
+
+	let expanded = Border::Some(line).expand();
+	assert_eq!(expanded.len(), 12);
+	assert!(expanded.contains(&Property::BorderLeftWidth(BorderWidth::Some(Unit::Px(F32::new_unwrap(2.))))));
+	assert!(expanded.contains(&Property::BorderBottomStyle(BorderStyle::Solid)));
+	assert!(expanded.contains(&border_top_color!(0xFF_00_00_FF)));
+
+	assert_eq!(Outline::Some(BorderLine { style: Some(BorderStyle::Dashed), ..Default::default() }).expand(), vec![
+		outline_width!(initial),
+		outline_style!(dashed),
+		outline_color!(initial),
+	]);
+}
+
 #[test]
 fn border_width_values() {
 	assert_eq!(__border_width!(Left, medium).to_string(), "border-left-width:medium;");
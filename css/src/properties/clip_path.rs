@@ -39,19 +39,14 @@ pub enum ClipPath {
 impl std::fmt::Display for ClipPath {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
-			Self::None => "-webkit-clip-path:none;clip-path:none;".fmt(f),
-			Self::Initial => "-webkit-clip-path:initial;clip-path:initial;".fmt(f),
-			Self::Inherit => "-webkit-clip-path:inherit;clip-path:inherit;".fmt(f),
-			Self::Unset => "-webkit-clip-path:unset;clip-path:unset;".fmt(f),
-			Self::Url(x) => write!(f, r#"-webkit-clip-path:url("{0}");clip-path:url("{0}");"#, x),
+			Self::None => "clip-path:none;".fmt(f),
+			Self::Initial => "clip-path:initial;".fmt(f),
+			Self::Inherit => "clip-path:inherit;".fmt(f),
+			Self::Unset => "clip-path:unset;".fmt(f),
+			Self::Url(x) => write!(f, r#"clip-path:url("{}");"#, x),
 			Self::Shape(shapes) => {
 				if let Some((first, rest)) = shapes.split_first() {
-					"-webkit-clip-path:".fmt(f)?;
-					first.fmt(f)?;
-					for shape in rest {
-						write!(f, " {}", shape)?;
-					}
-					";clip-path:".fmt(f)?;
+					"clip-path:".fmt(f)?;
 					first.fmt(f)?;
 					for shape in rest {
 						write!(f, " {}", shape)?;
@@ -20,6 +20,14 @@ pub enum DimensionExtremity {
 	MinContent,
 }
 
+impl From<Unit> for Dimension {
+	fn from(x: Unit) -> Self { Self::Some(x) }
+}
+
+impl From<Unit> for DimensionExtremity {
+	fn from(x: Unit) -> Self { Self::Some(x) }
+}
+
 #[rustfmt::skip]
 impl std::fmt::Display for Dimension {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -61,6 +69,8 @@ macro_rules! __dimension {
 
 #[macro_export] macro_rules! width { ($($tt:tt)+) => {$crate::__dimension!(Width, $($tt)+)} }
 #[macro_export] macro_rules! height { ($($tt:tt)+) => {$crate::__dimension!(Height, $($tt)+)} }
+#[macro_export] macro_rules! inline_size { ($($tt:tt)+) => {$crate::__dimension!(InlineSize, $($tt)+)} }
+#[macro_export] macro_rules! block_size { ($($tt:tt)+) => {$crate::__dimension!(BlockSize, $($tt)+)} }
 
 #[rustfmt::skip]
 #[macro_export]
@@ -79,3 +89,7 @@ macro_rules! __dimension_extremity {
 #[macro_export] macro_rules! max_width { ($($tt:tt)+) => { $crate::__dimension_extremity!(MaxWidth, $($tt)+)} }
 #[macro_export] macro_rules! min_height { ($($tt:tt)+) => { $crate::__dimension_extremity!(MinHeight, $($tt)+)} }
 #[macro_export] macro_rules! max_height { ($($tt:tt)+) => { $crate::__dimension_extremity!(MaxHeight, $($tt)+)} }
+#[macro_export] macro_rules! min_inline_size { ($($tt:tt)+) => { $crate::__dimension_extremity!(MinInlineSize, $($tt)+)} }
+#[macro_export] macro_rules! max_inline_size { ($($tt:tt)+) => { $crate::__dimension_extremity!(MaxInlineSize, $($tt)+)} }
+#[macro_export] macro_rules! min_block_size { ($($tt:tt)+) => { $crate::__dimension_extremity!(MinBlockSize, $($tt)+)} }
+#[macro_export] macro_rules! max_block_size { ($($tt:tt)+) => { $crate::__dimension_extremity!(MaxBlockSize, $($tt)+)} }
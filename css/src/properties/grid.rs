@@ -3,15 +3,6 @@ use crate::prelude::*;
 css_macros::easy_enum! {row-gap normal [unit]}
 css_macros::easy_enum! {column-gap normal [unit]}
 
-/*
-css::grid!(
-	column,
-	// [*-gap] | [grid-template-*] >> [grid-auto-*]
-	columns (32 px) | (1 fr) (50 px) repeat(5, (1 fr)) >> 1 fr,
-	rows >> 1 fr,
-)
-*/
-
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum GridSpan {
 	Auto,
@@ -96,7 +87,7 @@ impl std::fmt::Display for GridRepeatNumber {
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct GridRepeat {
 	pub number: GridRepeatNumber,
-	pub values: Vec<Unit>,
+	pub values: Vec<GridTemplateValue>,
 }
 
 #[rustfmt::skip]
@@ -127,15 +118,38 @@ impl std::fmt::Display for GridTemplate {
 			Self::Initial     => "initial".fmt(f),
 			Self::Unset       => "unset".fmt(f),
 			Self::None        => "none".fmt(f),
-			Self::Some(values)     => {
-				if let Some((first, rest)) = values.split_first() {
-					write!(f, "{}", first)?;
-					for value in rest {
-						write!(f, " {}", value)?;
-					}
-				}
-				Ok(())
-			},
+			Self::Some(values) => write_track_list(f, values),
+		}
+	}
+}
+
+fn write_track_list(f: &mut std::fmt::Formatter<'_>, values: &[GridTemplateValue]) -> std::fmt::Result {
+	if let Some((first, rest)) = values.split_first() {
+		write!(f, "{}", first)?;
+		for value in rest {
+			write!(f, " {}", value)?;
+		}
+	}
+	Ok(())
+}
+
+/// A track size that can be used as either argument of `minmax()`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum GridBreadth {
+	Auto,
+	MinContent,
+	MaxContent,
+	Unit(Unit),
+}
+
+#[rustfmt::skip]
+impl std::fmt::Display for GridBreadth {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Auto       => "auto".fmt(f),
+			Self::MinContent => "min-content".fmt(f),
+			Self::MaxContent => "max-content".fmt(f),
+			Self::Unit(x)    => x.fmt(f),
 		}
 	}
 }
@@ -144,14 +158,26 @@ impl std::fmt::Display for GridTemplate {
 pub enum GridTemplateValue {
 	Repeat(GridRepeat),
 	Unit(Unit),
+	Auto,
+	MinContent,
+	MaxContent,
+	MinMax(GridBreadth, GridBreadth),
+	FitContent(Unit),
+	LineNames(Vec<String>),
 }
 
 #[rustfmt::skip]
 impl std::fmt::Display for GridTemplateValue {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
-			Self::Unit(x) => x.fmt(f),
-			Self::Repeat(x) => x.fmt(f),
+			Self::Unit(x)            => x.fmt(f),
+			Self::Repeat(x)          => x.fmt(f),
+			Self::Auto               => "auto".fmt(f),
+			Self::MinContent         => "min-content".fmt(f),
+			Self::MaxContent         => "max-content".fmt(f),
+			Self::MinMax(min, max)   => write!(f, "minmax({},{})", min, max),
+			Self::FitContent(x)      => write!(f, "fit-content({})", x),
+			Self::LineNames(names)   => write!(f, "[{}]", names.join(" ")),
 		}
 	}
 }
@@ -186,6 +212,346 @@ impl std::fmt::Display for GridAuto {
 	}
 }
 
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum GridTemplateAreas {
+	None,
+	Initial,
+	Inherit,
+	Unset,
+	Some(Vec<String>),
+}
+
+#[rustfmt::skip]
+impl std::fmt::Display for GridTemplateAreas {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::None    => "grid-template-areas:none;".fmt(f),
+			Self::Initial => "grid-template-areas:initial;".fmt(f),
+			Self::Inherit => "grid-template-areas:inherit;".fmt(f),
+			Self::Unset   => "grid-template-areas:unset;".fmt(f),
+			Self::Some(rows) => {
+				"grid-template-areas:".fmt(f)?;
+				if let Some((first, rest)) = rows.split_first() {
+					write!(f, r#""{}""#, first)?;
+					for row in rest {
+						write!(f, r#" "{}""#, row)?;
+					}
+				}
+				";".fmt(f)
+			},
+		}
+	}
+}
+
+#[macro_export]
+macro_rules! grid_template_areas {
+	(none)           => {$crate::Property::GridTemplateAreas($crate::GridTemplateAreas::None)};
+	(initial)        => {$crate::Property::GridTemplateAreas($crate::GridTemplateAreas::Initial)};
+	(inherit)        => {$crate::Property::GridTemplateAreas($crate::GridTemplateAreas::Inherit)};
+	(unset)          => {$crate::Property::GridTemplateAreas($crate::GridTemplateAreas::Unset)};
+	($($row:expr),+) => {$crate::Property::GridTemplateAreas($crate::GridTemplateAreas::Some(vec![$($row.into()),+]))};
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum GridArea {
+	Auto,
+	Initial,
+	Inherit,
+	Unset,
+	Name(String),
+	Lines {
+		row_start: GridSpan,
+		column_start: GridSpan,
+		row_end: GridSpan,
+		column_end: GridSpan,
+	},
+}
+
+#[rustfmt::skip]
+impl std::fmt::Display for GridArea {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Auto    => "grid-area:auto;".fmt(f),
+			Self::Initial => "grid-area:initial;".fmt(f),
+			Self::Inherit => "grid-area:inherit;".fmt(f),
+			Self::Unset   => "grid-area:unset;".fmt(f),
+			Self::Name(x) => write!(f, "grid-area:{};", x),
+			Self::Lines { row_start, column_start, row_end, column_end } => write!(f, "grid-area:{} / {} / {} / {};", row_start, column_start, row_end, column_end),
+		}
+	}
+}
+
+#[macro_export]
+macro_rules! grid_area {
+	(auto)            => {$crate::Property::GridArea($crate::GridArea::Auto)};
+	(initial)         => {$crate::Property::GridArea($crate::GridArea::Initial)};
+	(inherit)         => {$crate::Property::GridArea($crate::GridArea::Inherit)};
+	(unset)           => {$crate::Property::GridArea($crate::GridArea::Unset)};
+	($($name:ident)-+) => {$crate::Property::GridArea($crate::GridArea::Name(vec![$(stringify!($name)),+].join("-")))};
+}
+
+/// The `grid-template` shorthand
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum GridTemplateShorthand {
+	None,
+	Initial,
+	Inherit,
+	Unset,
+	/// `<rows> / <columns>`
+	Tracks {
+		rows: Vec<GridTemplateValue>,
+		columns: Vec<GridTemplateValue>,
+	},
+	/// `"<area names>" <row size>? ... / <columns>?`
+	Areas {
+		rows: Vec<(String, Option<GridTemplateValue>)>,
+		columns: Vec<GridTemplateValue>,
+	},
+}
+
+impl GridTemplateShorthand {
+	/// Write just the value, without the property name, e.g. for use in the `grid` shorthand
+	pub fn fmt_value(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::None    => f.write_str("none"),
+			Self::Initial => f.write_str("initial"),
+			Self::Inherit => f.write_str("inherit"),
+			Self::Unset   => f.write_str("unset"),
+			Self::Tracks { rows, columns } => {
+				write_track_list(f, rows)?;
+				f.write_str(" / ")?;
+				write_track_list(f, columns)
+			},
+			Self::Areas { rows, columns } => {
+				let mut sep = "";
+				for (areas, size) in rows {
+					write!(f, r#"{}"{}""#, sep, areas)?;
+					if let Some(size) = size { write!(f, " {}", size)?; }
+					sep = " ";
+				}
+				if !columns.is_empty() {
+					f.write_str(" / ")?;
+					write_track_list(f, columns)?;
+				}
+				Ok(())
+			},
+		}
+	}
+
+	/// The long-hand properties equivalent to this shorthand
+	pub fn expand(&self) -> Vec<crate::Property> {
+		use crate::Property;
+
+		let (rows, columns, areas) = match self {
+			Self::None    => (GridTemplate::None, GridTemplate::None, GridTemplateAreas::None),
+			Self::Initial => (GridTemplate::Initial, GridTemplate::Initial, GridTemplateAreas::Initial),
+			Self::Inherit => (GridTemplate::Inherit, GridTemplate::Inherit, GridTemplateAreas::Inherit),
+			Self::Unset   => (GridTemplate::Unset, GridTemplate::Unset, GridTemplateAreas::Unset),
+			Self::Tracks { rows, columns } => (GridTemplate::Some(rows.clone()), GridTemplate::Some(columns.clone()), GridTemplateAreas::None),
+			Self::Areas { rows, columns } => (
+				GridTemplate::Some(rows.iter().map(|(_, size)| size.clone().unwrap_or(GridTemplateValue::Auto)).collect()),
+				if columns.is_empty() { GridTemplate::None } else { GridTemplate::Some(columns.clone()) },
+				GridTemplateAreas::Some(rows.iter().map(|(areas, _)| areas.clone()).collect()),
+			),
+		};
+
+		vec![Property::GridTemplateRows(rows), Property::GridTemplateColumns(columns), Property::GridTemplateAreas(areas)]
+	}
+}
+
+impl std::fmt::Display for GridTemplateShorthand {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		"grid-template:".fmt(f)?;
+		self.fmt_value(f)?;
+		";".fmt(f)
+	}
+}
+
+/// The `grid` shorthand
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Grid {
+	Initial,
+	Inherit,
+	Unset,
+	Template(GridTemplateShorthand),
+	/// `<rows> / auto-flow dense? <auto columns>?`
+	AutoColumns {
+		rows: Vec<GridTemplateValue>,
+		dense: bool,
+		columns: Vec<Unit>,
+	},
+	/// `auto-flow dense? <auto rows>? / <columns>`
+	AutoRows {
+		dense: bool,
+		rows: Vec<Unit>,
+		columns: Vec<GridTemplateValue>,
+	},
+}
+
+impl std::fmt::Display for Grid {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		"grid:".fmt(f)?;
+		match self {
+			Self::Initial => "initial".fmt(f)?,
+			Self::Inherit => "inherit".fmt(f)?,
+			Self::Unset   => "unset".fmt(f)?,
+			Self::Template(x) => x.fmt_value(f)?,
+			Self::AutoColumns { rows, dense, columns } => {
+				write_track_list(f, rows)?;
+				" / auto-flow".fmt(f)?;
+				if *dense { " dense".fmt(f)?; }
+				for unit in columns { write!(f, " {}", unit)?; }
+			},
+			Self::AutoRows { dense, rows, columns } => {
+				"auto-flow".fmt(f)?;
+				if *dense { " dense".fmt(f)?; }
+				for unit in rows { write!(f, " {}", unit)?; }
+				" / ".fmt(f)?;
+				write_track_list(f, columns)?;
+			},
+		}
+		";".fmt(f)
+	}
+}
+
+impl Grid {
+	/// The long-hand properties equivalent to this shorthand
+	pub fn expand(&self) -> Vec<crate::Property> {
+		use crate::Property;
+
+		fn auto(units: &[Unit]) -> GridAuto {
+			if units.is_empty() { GridAuto::Auto } else { GridAuto::Some(units.to_vec()) }
+		}
+
+		let (mut properties, auto_rows, auto_columns, auto_flow) = match self {
+			Self::Initial => (GridTemplateShorthand::Initial.expand(), GridAuto::Initial, GridAuto::Initial, GridAutoFlow::Initial),
+			Self::Inherit => (GridTemplateShorthand::Inherit.expand(), GridAuto::Inherit, GridAuto::Inherit, GridAutoFlow::Inherit),
+			Self::Unset   => (GridTemplateShorthand::Unset.expand(), GridAuto::Unset, GridAuto::Unset, GridAutoFlow::Unset),
+			Self::Template(x) => (x.expand(), GridAuto::Initial, GridAuto::Initial, GridAutoFlow::Initial),
+			Self::AutoColumns { rows, dense, columns } => (
+				vec![
+					Property::GridTemplateRows(GridTemplate::Some(rows.clone())),
+					Property::GridTemplateColumns(GridTemplate::None),
+					Property::GridTemplateAreas(GridTemplateAreas::None),
+				],
+				GridAuto::Initial,
+				auto(columns),
+				if *dense { GridAutoFlow::ColumnDense } else { GridAutoFlow::Column },
+			),
+			Self::AutoRows { dense, rows, columns } => (
+				vec![
+					Property::GridTemplateRows(GridTemplate::None),
+					Property::GridTemplateColumns(GridTemplate::Some(columns.clone())),
+					Property::GridTemplateAreas(GridTemplateAreas::None),
+				],
+				auto(rows),
+				GridAuto::Initial,
+				if *dense { GridAutoFlow::RowDense } else { GridAutoFlow::Row },
+			),
+		};
+
+		properties.extend(vec![Property::GridAutoRows(auto_rows), Property::GridAutoColumns(auto_columns), Property::GridAutoFlow(auto_flow)]);
+		properties
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PlaceItems {
+	pub align: crate::AlignItems,
+	pub justify: crate::JustifyItems,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PlaceContent {
+	pub align: crate::AlignContent,
+	pub justify: crate::JustifyContent,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PlaceSelf {
+	pub align: crate::AlignSelf,
+	pub justify: crate::JustifySelf,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Gap {
+	pub row: RowGap,
+	pub column: ColumnGap,
+}
+
+// shorthands for a pair of long-hands, the second value is left out if it's the same as the first
+macro_rules! pair_shorthand {
+	($($name:ident($css_name:expr) => $first:ident: $first_property:ident, $second:ident: $second_property:ident;)*) => {$(
+		impl std::fmt::Display for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				let first = crate::display_fn(|f| self.$first.fmt_value(f)).to_string();
+				let second = crate::display_fn(|f| self.$second.fmt_value(f)).to_string();
+				if first == second {
+					write!(f, "{}:{};", $css_name, first)
+				} else {
+					write!(f, "{}:{} {};", $css_name, first, second)
+				}
+			}
+		}
+
+		impl $name {
+			/// The long-hand properties equivalent to this shorthand
+			pub fn expand(&self) -> Vec<crate::Property> {
+				vec![crate::Property::$first_property(self.$first.clone()), crate::Property::$second_property(self.$second.clone())]
+			}
+		}
+	)*};
+}
+
+pair_shorthand! {
+	PlaceItems("place-items") => align: AlignItems, justify: JustifyItems;
+	PlaceContent("place-content") => align: AlignContent, justify: JustifyContent;
+	PlaceSelf("place-self") => align: AlignSelf, justify: JustifySelf;
+	Gap("gap") => row: RowGap, column: ColumnGap;
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __property_value {
+	($variant:ident, $property:expr) => {match $property { $crate::Property::$variant(x) => x, _ => unreachable!() }};
+}
+
+#[macro_export]
+macro_rules! place_items {
+	($($align:ident)-+, $($justify:ident)-+) => {$crate::Property::PlaceItems($crate::PlaceItems {
+		align: $crate::__property_value!(AlignItems, $crate::align_items!($($align)-+)),
+		justify: $crate::__property_value!(JustifyItems, $crate::justify_items!($($justify)-+)),
+	})};
+	($($value:ident)-+) => {$crate::place_items!($($value)-+, $($value)-+)};
+}
+
+#[macro_export]
+macro_rules! place_content {
+	($($align:ident)-+, $($justify:ident)-+) => {$crate::Property::PlaceContent($crate::PlaceContent {
+		align: $crate::__property_value!(AlignContent, $crate::align_content!($($align)-+)),
+		justify: $crate::__property_value!(JustifyContent, $crate::justify_content!($($justify)-+)),
+	})};
+	($($value:ident)-+) => {$crate::place_content!($($value)-+, $($value)-+)};
+}
+
+#[macro_export]
+macro_rules! place_self {
+	($($align:ident)-+, $($justify:ident)-+) => {$crate::Property::PlaceSelf($crate::PlaceSelf {
+		align: $crate::__property_value!(AlignSelf, $crate::align_self!($($align)-+)),
+		justify: $crate::__property_value!(JustifySelf, $crate::justify_self!($($justify)-+)),
+	})};
+	($($value:ident)-+) => {$crate::place_self!($($value)-+, $($value)-+)};
+}
+
+#[macro_export]
+macro_rules! gap {
+	(($($row:tt)+), ($($column:tt)+)) => {$crate::Property::Gap($crate::Gap {
+		row: $crate::__property_value!(RowGap, $crate::row_gap!($($row)+)),
+		column: $crate::__property_value!(ColumnGap, $crate::column_gap!($($column)+)),
+	})};
+	($($value:tt)+) => {$crate::gap!(($($value)+), ($($value)+))};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __repeat_number {
@@ -206,16 +572,56 @@ macro_rules! __repeat {
 	($($repeat_number:tt)-+, $($v:tt)+) => {
 		$crate::GridRepeat {
 			number: $crate::__repeat_number!($($repeat_number)-+),
-			values: vec![$($crate::__nested_unit!($v)),+],
+			values: $crate::__grid_track_list!(acc = (), rest = ($($v)+)),
 		}
 	}
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __grid_breadth {
+	(auto)          => {$crate::GridBreadth::Auto};
+	(min-content)   => {$crate::GridBreadth::MinContent};
+	(max-content)   => {$crate::GridBreadth::MaxContent};
+	(($($tt:tt)+))  => {$crate::GridBreadth::Unit($crate::unit!($($tt)+))};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __grid_template_value {
-	(repeat($($tt:tt)+)) => {$crate::GridTemplateValue::Repeat($crate::__repeat!($($tt)+))};
-	(($($tt:tt)+)) => {$crate::GridTemplateValue::Unit($crate::unit!($($tt)+))};
+	(repeat($($tt:tt)+))                   => {$crate::GridTemplateValue::Repeat($crate::__repeat!($($tt)+))};
+	(minmax($($min:tt)-+, $($max:tt)-+))   => {$crate::GridTemplateValue::MinMax($crate::__grid_breadth!($($min)-+), $crate::__grid_breadth!($($max)-+))};
+	(fit-content($($tt:tt)+))              => {$crate::GridTemplateValue::FitContent($crate::unit!($($tt)+))};
+	(auto)                                 => {$crate::GridTemplateValue::Auto};
+	(min-content)                          => {$crate::GridTemplateValue::MinContent};
+	(max-content)                          => {$crate::GridTemplateValue::MaxContent};
+	([$($($name:ident)-+)*])               => {$crate::GridTemplateValue::LineNames(vec![$(vec![$(stringify!($name)),+].join("-")),*])};
+	(($($tt:tt)+))                         => {$crate::GridTemplateValue::Unit($crate::unit!($($tt)+))};
+}
+
+// munches a space separated list of track sizes, line names and repeats
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __grid_track_list {
+	(acc = ($($acc:expr,)*), rest = ()) => {vec![$($acc),*]};
+	(acc = ($($acc:expr,)*), rest = (repeat($($tt:tt)+) $($rest:tt)*)) => {
+		$crate::__grid_track_list!(acc = ($($acc,)* $crate::__grid_template_value!(repeat($($tt)+)),), rest = ($($rest)*))
+	};
+	(acc = ($($acc:expr,)*), rest = (minmax($($tt:tt)+) $($rest:tt)*)) => {
+		$crate::__grid_track_list!(acc = ($($acc,)* $crate::__grid_template_value!(minmax($($tt)+)),), rest = ($($rest)*))
+	};
+	(acc = ($($acc:expr,)*), rest = (fit-content($($tt:tt)+) $($rest:tt)*)) => {
+		$crate::__grid_track_list!(acc = ($($acc,)* $crate::__grid_template_value!(fit-content($($tt)+)),), rest = ($($rest)*))
+	};
+	(acc = ($($acc:expr,)*), rest = (min-content $($rest:tt)*)) => {
+		$crate::__grid_track_list!(acc = ($($acc,)* $crate::GridTemplateValue::MinContent,), rest = ($($rest)*))
+	};
+	(acc = ($($acc:expr,)*), rest = (max-content $($rest:tt)*)) => {
+		$crate::__grid_track_list!(acc = ($($acc,)* $crate::GridTemplateValue::MaxContent,), rest = ($($rest)*))
+	};
+	(acc = ($($acc:expr,)*), rest = ($value:tt $($rest:tt)*)) => {
+		$crate::__grid_track_list!(acc = ($($acc,)* $crate::__grid_template_value!($value),), rest = ($($rest)*))
+	};
 }
 
 #[doc(hidden)]
@@ -227,7 +633,7 @@ macro_rules! __grid_template {
 		current = ($($current:tt)+),
 		rest = ($(,)*),
 	) => {{
-		$acc.push($crate::__grid_template_value!($($current)+));
+		$acc.extend($crate::__grid_track_list!(acc = (), rest = ($($current)+)));
 		let acc = $acc;
 		$crate::paste::item!{$crate::Property::[<GridTemplate $kind>]($crate::GridTemplate::Some(acc))}
 	}};
@@ -237,7 +643,7 @@ macro_rules! __grid_template {
 		current = ($($current:tt)+),
 		rest = (, $($rest:tt)*),
 	) => {{
-		$acc.push($crate::__grid_template_value!($($current)+));
+		$acc.extend($crate::__grid_track_list!(acc = (), rest = ($($current)+)));
 		$crate::__grid_template!(
 			kind = $kind,
 			acc = $acc,
@@ -262,6 +668,10 @@ macro_rules! __grid_template {
 
 #[macro_export]
 macro_rules! grid_template_columns {
+	(none)    => {$crate::Property::GridTemplateColumns($crate::GridTemplate::None)};
+	(initial) => {$crate::Property::GridTemplateColumns($crate::GridTemplate::Initial)};
+	(inherit) => {$crate::Property::GridTemplateColumns($crate::GridTemplate::Inherit)};
+	(unset)   => {$crate::Property::GridTemplateColumns($crate::GridTemplate::Unset)};
 	($($tt:tt)+) => {{
 		let mut acc = Vec::new();
 		$crate::__grid_template!(
@@ -275,6 +685,10 @@ macro_rules! grid_template_columns {
 
 #[macro_export]
 macro_rules! grid_template_rows {
+	(none)    => {$crate::Property::GridTemplateRows($crate::GridTemplate::None)};
+	(initial) => {$crate::Property::GridTemplateRows($crate::GridTemplate::Initial)};
+	(inherit) => {$crate::Property::GridTemplateRows($crate::GridTemplate::Inherit)};
+	(unset)   => {$crate::Property::GridTemplateRows($crate::GridTemplate::Unset)};
 	($($tt:tt)+) => {{
 		let mut acc = Vec::new();
 		$crate::__grid_template!(
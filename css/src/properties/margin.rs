@@ -11,6 +11,10 @@ pub enum Margin {
 	Some(Unit),
 }
 
+impl From<Unit> for Margin {
+	fn from(x: Unit) -> Self { Self::Some(x) }
+}
+
 #[rustfmt::skip]
 impl std::fmt::Display for Margin {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -41,3 +45,7 @@ macro_rules! __margin {
 #[macro_export] macro_rules! margin_right { ($($tt:tt)+) => { $crate::__margin!(Right, $($tt)+)} }
 #[macro_export] macro_rules! margin_top { ($($tt:tt)+) => { $crate::__margin!(Top, $($tt)+)} }
 #[macro_export] macro_rules! margin_bottom { ($($tt:tt)+) => { $crate::__margin!(Bottom, $($tt)+)} }
+#[macro_export] macro_rules! margin_inline_start { ($($tt:tt)+) => { $crate::__margin!(InlineStart, $($tt)+)} }
+#[macro_export] macro_rules! margin_inline_end { ($($tt:tt)+) => { $crate::__margin!(InlineEnd, $($tt)+)} }
+#[macro_export] macro_rules! margin_block_start { ($($tt:tt)+) => { $crate::__margin!(BlockStart, $($tt)+)} }
+#[macro_export] macro_rules! margin_block_end { ($($tt:tt)+) => { $crate::__margin!(BlockEnd, $($tt)+)} }
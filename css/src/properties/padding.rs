@@ -4,3 +4,7 @@ css_macros::unit_value_macro! {padding_left PaddingLeft}
 css_macros::unit_value_macro! {padding_right PaddingRight}
 css_macros::unit_value_macro! {padding_top PaddingTop}
 css_macros::unit_value_macro! {padding_bottom PaddingBottom}
+css_macros::unit_value_macro! {padding_inline_start PaddingInlineStart}
+css_macros::unit_value_macro! {padding_inline_end PaddingInlineEnd}
+css_macros::unit_value_macro! {padding_block_start PaddingBlockStart}
+css_macros::unit_value_macro! {padding_block_end PaddingBlockEnd}
@@ -7,3 +7,7 @@ css_macros::easy_enum! {z-index auto [number]}
 #[macro_export] macro_rules! right { ($($tt:tt)+) => {$crate::__dimension!(Right, $($tt)+)} }
 #[macro_export] macro_rules! bottom { ($($tt:tt)+) => {$crate::__dimension!(Bottom, $($tt)+)} }
 #[macro_export] macro_rules! left { ($($tt:tt)+) => {$crate::__dimension!(Left, $($tt)+)} }
+#[macro_export] macro_rules! inset_inline_start { ($($tt:tt)+) => {$crate::__dimension!(InsetInlineStart, $($tt)+)} }
+#[macro_export] macro_rules! inset_inline_end { ($($tt:tt)+) => {$crate::__dimension!(InsetInlineEnd, $($tt)+)} }
+#[macro_export] macro_rules! inset_block_start { ($($tt:tt)+) => {$crate::__dimension!(InsetBlockStart, $($tt)+)} }
+#[macro_export] macro_rules! inset_block_end { ($($tt:tt)+) => {$crate::__dimension!(InsetBlockEnd, $($tt)+)} }
@@ -84,6 +84,148 @@ fn font_family_values() {
 	assert_eq!(font_family!("Helvetica", "Arial", "sans-serif").to_string(), r#"font-family:"Helvetica","Arial","sans-serif";"#);
 }
 
+/// The components of the `font` shorthand, left out components are reset to their initial values
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct FontSpec {
+	pub style: Option<FontStyle>,
+	pub variant: Option<FontVariant>,
+	pub weight: Option<FontWeight>,
+	pub stretch: Option<FontStretch>,
+	pub size: FontSize,
+	pub line_height: Option<LineHeight>,
+	pub family: Vec<String>,
+}
+
+impl std::fmt::Display for FontSpec {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if let Some(x) = &self.style { x.fmt_value(f)?; " ".fmt(f)?; }
+		if let Some(x) = &self.variant { x.fmt_value(f)?; " ".fmt(f)?; }
+		if let Some(x) = &self.weight { x.fmt_value(f)?; " ".fmt(f)?; }
+		if let Some(x) = &self.stretch { x.fmt_value(f)?; " ".fmt(f)?; }
+		self.size.fmt_value(f)?;
+		if let Some(x) = &self.line_height { "/".fmt(f)?; x.fmt_value(f)?; }
+		if let Some((first, rest)) = self.family.split_first() {
+			write!(f, r#" "{}""#, first)?;
+			for font in rest {
+				write!(f, r#","{}""#, font)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[rustfmt::skip]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Font {
+	Initial,
+	Inherit,
+	Unset,
+	Caption,
+	Icon,
+	Menu,
+	MessageBox,
+	SmallCaption,
+	StatusBar,
+	Some(FontSpec),
+}
+
+#[rustfmt::skip]
+impl std::fmt::Display for Font {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Initial      => "font:initial;".fmt(f),
+			Self::Inherit      => "font:inherit;".fmt(f),
+			Self::Unset        => "font:unset;".fmt(f),
+			Self::Caption      => "font:caption;".fmt(f),
+			Self::Icon         => "font:icon;".fmt(f),
+			Self::Menu         => "font:menu;".fmt(f),
+			Self::MessageBox   => "font:message-box;".fmt(f),
+			Self::SmallCaption => "font:small-caption;".fmt(f),
+			Self::StatusBar    => "font:status-bar;".fmt(f),
+			Self::Some(spec)   => write!(f, "font:{};", spec),
+		}
+	}
+}
+
+impl Font {
+	/// The long-hand properties equivalent to this shorthand
+	///
+	/// System fonts can't be expressed with long-hands, so they're kept as is
+	pub fn expand(&self) -> Vec<crate::Property> {
+		use crate::Property;
+
+		macro_rules! keyword {
+			($kw:ident) => {vec![
+				Property::FontStyle(FontStyle::$kw),
+				Property::FontVariant(FontVariant::$kw),
+				Property::FontWeight(FontWeight::$kw),
+				Property::FontStretch(FontStretch::$kw),
+				Property::FontSize(FontSize::$kw),
+				Property::LineHeight(LineHeight::$kw),
+				Property::FontFamily(FontFamily::$kw),
+			]};
+		}
+
+		match self {
+			Self::Initial => keyword!(Initial),
+			Self::Inherit => keyword!(Inherit),
+			Self::Unset   => keyword!(Unset),
+			Self::Some(spec) => vec![
+				Property::FontStyle(spec.style.clone().unwrap_or(FontStyle::Initial)),
+				Property::FontVariant(spec.variant.clone().unwrap_or(FontVariant::Initial)),
+				Property::FontWeight(spec.weight.clone().unwrap_or(FontWeight::Initial)),
+				Property::FontStretch(spec.stretch.clone().unwrap_or(FontStretch::Initial)),
+				Property::FontSize(spec.size.clone()),
+				Property::LineHeight(spec.line_height.clone().unwrap_or(LineHeight::Initial)),
+				Property::FontFamily(FontFamily::Some(spec.family.clone())),
+			],
+			system => vec![Property::Font(system.clone())],
+		}
+	}
+}
+
+#[rustfmt::skip]
+#[macro_export]
+macro_rules! font {
+	(initial)       => {$crate::Property::Font($crate::Font::Initial)};
+	(inherit)       => {$crate::Property::Font($crate::Font::Inherit)};
+	(unset)         => {$crate::Property::Font($crate::Font::Unset)};
+	(caption)       => {$crate::Property::Font($crate::Font::Caption)};
+	(icon)          => {$crate::Property::Font($crate::Font::Icon)};
+	(menu)          => {$crate::Property::Font($crate::Font::Menu)};
+	(message-box)   => {$crate::Property::Font($crate::Font::MessageBox)};
+	(small-caption) => {$crate::Property::Font($crate::Font::SmallCaption)};
+	(status-bar)    => {$crate::Property::Font($crate::Font::StatusBar)};
+	($spec:expr)    => {$crate::Property::Font($crate::Font::Some($spec))};
+}
+
+#[test]
+fn font_shorthand() {
+	let spec = FontSpec {
+		style: Some(FontStyle::Italic),
+		variant: None,
+		weight: Some(FontWeight::Number(500)),
+		stretch: None,
+		size: FontSize::Some(Unit::Px(F32::new_unwrap(18.))),
+		line_height: Some(LineHeight::Number(F32::new_unwrap(1.2))),
+		family: vec!["Roboto".into(), "sans-serif".into()],
+	};
+	assert_eq!(font!(spec.clone()).to_string(), r#"font:italic 500 18px/1.2 "Roboto","sans-serif";"#);
+	assert_eq!(font!(message-box).to_string(), "font:message-box;");
+	assert_eq!(font!(initial).to_string(), "font:initial;");
+
+	assert_eq!(Font::Some(spec).expand(), vec![
+		font_style!(italic),
+		font_variant!(initial),
+		font_weight!(500),
+		font_stretch!(initial),
+		crate::Property::FontSize(FontSize::Some(Unit::Px(F32::new_unwrap(18.)))),
+		crate::Property::LineHeight(LineHeight::Number(F32::new_unwrap(1.2))),
+		font_family!("Roboto", "sans-serif"),
+	]);
+	assert_eq!(Font::Menu.expand(), vec![font!(menu)]);
+}
+
 // css::font!(
 //     font "Roboto" 500 italic normal,
 //     size 18 px,
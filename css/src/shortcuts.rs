@@ -1,4 +1,7 @@
 mod flexbox;
+mod grid;
+
+pub use flexbox::BoxLayout;
 
 #[macro_export] macro_rules! margin_vertical { ($($tt:tt)+) => {
 	vec![
@@ -12,6 +15,18 @@ mod flexbox;
 		$crate::__margin!(Right, $($tt)+),
 	]
 } }
+#[macro_export] macro_rules! margin_inline { ($($tt:tt)+) => {
+	vec![
+		$crate::__margin!(InlineStart, $($tt)+),
+		$crate::__margin!(InlineEnd, $($tt)+),
+	]
+} }
+#[macro_export] macro_rules! margin_block { ($($tt:tt)+) => {
+	vec![
+		$crate::__margin!(BlockStart, $($tt)+),
+		$crate::__margin!(BlockEnd, $($tt)+),
+	]
+} }
 #[macro_export] macro_rules! margin { ($($tt:tt)+) => {
 	vec![
 		$crate::__margin!(Left, $($tt)+),
@@ -33,6 +48,18 @@ mod flexbox;
 		$crate::padding_right!($($tt)+),
 	]
 } }
+#[macro_export] macro_rules! padding_inline { ($($tt:tt)+) => {
+	vec![
+		$crate::padding_inline_start!($($tt)+),
+		$crate::padding_inline_end!($($tt)+),
+	]
+} }
+#[macro_export] macro_rules! padding_block { ($($tt:tt)+) => {
+	vec![
+		$crate::padding_block_start!($($tt)+),
+		$crate::padding_block_end!($($tt)+),
+	]
+} }
 #[macro_export] macro_rules! padding { ($($tt:tt)+) => {
 	vec![
 		$crate::padding_left!($($tt)+),
@@ -57,3 +84,29 @@ macro_rules! size {
 		$crate::height!($($tt)+),
 	]}
 }
+
+#[macro_export]
+macro_rules! inset_inline {
+	($($tt:tt)+) => {vec![
+		$crate::inset_inline_start!($($tt)+),
+		$crate::inset_inline_end!($($tt)+),
+	]}
+}
+
+#[macro_export]
+macro_rules! inset_block {
+	($($tt:tt)+) => {vec![
+		$crate::inset_block_start!($($tt)+),
+		$crate::inset_block_end!($($tt)+),
+	]}
+}
+
+#[macro_export]
+macro_rules! inset {
+	($($tt:tt)+) => {vec![
+		$crate::top!($($tt)+),
+		$crate::right!($($tt)+),
+		$crate::bottom!($($tt)+),
+		$crate::left!($($tt)+),
+	]}
+}
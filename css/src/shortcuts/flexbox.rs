@@ -1,3 +1,104 @@
+use crate::prelude::*;
+use crate::{
+	AlignContent, AlignItems, AppendProperty, Dimension, DimensionExtremity, Display, FlexDirection, FlexWrap, JustifyContent, Margin,
+	Position, Property, UnitValue,
+};
+
+macro_rules! box_layout {
+	($($field:ident: $type:ident => $variant:ident),*$(,)?) => {
+		/// Handles an element's position and size, i.e. the bits that influence position of other elements on the page,
+		/// as well as how it lays out its own children
+		///
+		/// `box-sizing: border-box` and `flex-shrink: 0` are always appended as it's what you'd almost certainly want
+		#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+		pub struct BoxLayout {
+			$(pub $field: Option<$type>,)*
+			/// Anything else that was `set`, appended after everything else
+			pub other: Vec<Property>,
+		}
+
+		impl BoxLayout {
+			$(
+				pub fn $field(mut self, x: impl Into<$type>) -> Self { self.$field = Some(x.into()); self }
+			)*
+
+			/// Store a property in its field, properties that don't belong to a box layout are kept in `other`
+			pub fn set(&mut self, property: Property) {
+				match property {
+					$(Property::$variant(x) => self.$field = Some(x),)*
+					x => self.other.push(x),
+				}
+			}
+
+			/// Override fields of `self` with the fields that are set in `other`
+			pub fn merge(mut self, other: Self) -> Self {
+				$(if other.$field.is_some() { self.$field = other.$field; })*
+				self.other.extend(other.other);
+				self
+			}
+		}
+
+		impl AppendProperty for BoxLayout {
+			fn append_property(self, decls: &mut Vec<Property>) {
+				decls.push(Property::BoxSizing(crate::BoxSizing::BorderBox));
+				decls.push(Property::FlexShrink(crate::FlexShrink::Number(crate::F32::new_unwrap(0.))));
+				$(if let Some(x) = self.$field { decls.push(Property::$variant(x)); })*
+				decls.extend(self.other);
+			}
+		}
+	};
+}
+
+box_layout! {
+	display: Display => Display,
+	position: Position => Position,
+
+	width: Dimension => Width,
+	height: Dimension => Height,
+	min_width: DimensionExtremity => MinWidth,
+	max_width: DimensionExtremity => MaxWidth,
+	min_height: DimensionExtremity => MinHeight,
+	max_height: DimensionExtremity => MaxHeight,
+
+	top: Dimension => Top,
+	right: Dimension => Right,
+	bottom: Dimension => Bottom,
+	left: Dimension => Left,
+
+	margin_top: Margin => MarginTop,
+	margin_right: Margin => MarginRight,
+	margin_bottom: Margin => MarginBottom,
+	margin_left: Margin => MarginLeft,
+
+	padding_top: UnitValue => PaddingTop,
+	padding_right: UnitValue => PaddingRight,
+	padding_bottom: UnitValue => PaddingBottom,
+	padding_left: UnitValue => PaddingLeft,
+
+	flex_direction: FlexDirection => FlexDirection,
+	flex_wrap: FlexWrap => FlexWrap,
+	align_items: AlignItems => AlignItems,
+	justify_content: JustifyContent => JustifyContent,
+	align_content: AlignContent => AlignContent,
+}
+
+impl BoxLayout {
+	pub fn new() -> Self { Self::default() }
+	pub fn flex() -> Self { Self::default().display(Display::Flex) }
+
+	pub fn with(mut self, property: Property) -> Self { self.set(property); self }
+
+	pub fn size(self, x: impl Into<Dimension>) -> Self { let x = x.into(); self.width(x).height(x) }
+
+	pub fn margin(self, x: impl Into<Margin>) -> Self { let x = x.into(); self.margin_horizontal(x).margin_vertical(x) }
+	pub fn margin_horizontal(self, x: impl Into<Margin>) -> Self { let x = x.into(); self.margin_left(x).margin_right(x) }
+	pub fn margin_vertical(self, x: impl Into<Margin>) -> Self { let x = x.into(); self.margin_top(x).margin_bottom(x) }
+
+	pub fn padding(self, x: impl Into<UnitValue>) -> Self { let x = x.into(); self.padding_horizontal(x).padding_vertical(x) }
+	pub fn padding_horizontal(self, x: impl Into<UnitValue>) -> Self { let x = x.into(); self.padding_left(x).padding_right(x) }
+	pub fn padding_vertical(self, x: impl Into<UnitValue>) -> Self { let x = x.into(); self.padding_top(x).padding_bottom(x) }
+}
+
 // wrap nowrap?
 // border?
 #[doc(hidden)]
@@ -21,43 +122,43 @@ macro_rules! __flexbox_line {
 	};
 
 	($acc:expr, $wrap:ident $($align_content:ident)-+) => {
-		$acc.push($crate::flex_wrap!($wrap));
-		$acc.push($crate::align_content!($($align_content)-+));
+		$acc.set($crate::flex_wrap!($wrap));
+		$acc.set($crate::align_content!($($align_content)-+));
 	};
 
 	($acc:expr, $direction:ident $($align:ident)-+ $($justify:ident)-+) => {
-		$acc.push($crate::flex_direction!($direction));
-		$acc.push($crate::align_items!($($align)-+));
-		$acc.push($crate::justify_content!($($justify)-+));
+		$acc.set($crate::flex_direction!($direction));
+		$acc.set($crate::align_items!($($align)-+));
+		$acc.set($crate::justify_content!($($justify)-+));
 	};
 	($acc:expr, $direction:ident) => {
-		$acc.push($crate::flex_direction!($direction));
+		$acc.set($crate::flex_direction!($direction));
 	};
 
 	($acc:expr, $dimension:ident ($($value:tt)*)) => {
-		$acc.push($crate::$dimension!($($value)*))
+		$acc.set($crate::$dimension!($($value)*))
 	};
 	($acc:expr, $dimension:ident ($($min:tt)*) ..) => {
 		let acc = &mut $acc;
-		paste::item!{$acc.push($crate::[<min_ $dimension>]!($($min)*));};
+		$crate::paste::item!{$acc.set($crate::[<min_ $dimension>]!($($min)*));};
 	};
 	($acc:expr, $dimension:ident .. ($($max:tt)*)) => {
 		let acc = &mut $acc;
-		paste::item!{$acc.push($crate::[<max_ $dimension>]!($($max)*));};
+		$crate::paste::item!{$acc.set($crate::[<max_ $dimension>]!($($max)*));};
 	};
 	($acc:expr, $dimension:ident ($($min:tt)*) .. ($($max:tt)*)) => {
 		let acc = &mut $acc;
-		paste::item!{
-			$acc.push($crate::[<min_ $dimension>]!($($min)*));
-			$acc.push($crate::[<max_ $dimension>]!($($max)*));
+		$crate::paste::item!{
+			$acc.set($crate::[<min_ $dimension>]!($($min)*));
+			$acc.set($crate::[<max_ $dimension>]!($($max)*));
 		};
 	};
 
 	($acc:expr, $side:ident ($($margin:tt)*) | ($($padding:tt)*)) => {
 		let acc = &mut $acc;
-		paste::item!{
-			acc.push($crate::[<margin_ $side>]!($($margin)*));
-			acc.push($crate::[<padding_ $side>]!($($padding)*));
+		$crate::paste::item!{
+			acc.set($crate::[<margin_ $side>]!($($margin)*));
+			acc.set($crate::[<padding_ $side>]!($($padding)*));
 		};
 	};
 	($acc:expr, [$($inner:tt)*]) => { $crate::__flexbox_line!($acc, $($inner)*) };
@@ -71,11 +172,7 @@ macro_rules! __flexbox_inner {
 		current_line = (),
 		rest = (),
 	) => {{
-		let mut acc = vec![
-			$crate::box_sizing!(border-box),
-			$crate::flex_shrink!(0),
-			$crate::display!(flex),
-		];
+		let mut acc = $crate::BoxLayout::flex();
 		$($crate::__flexbox_line!(acc, $line);)*
 		acc
 	}};
@@ -114,6 +211,7 @@ macro_rules! __flexbox_inner {
 	};
 }
 
+/// Sugar for building a `display: flex` `BoxLayout` line by line
 #[macro_export]
 macro_rules! flexbox {
 	($($tt:tt)*) => {
@@ -125,68 +223,6 @@ macro_rules! flexbox {
 	};
 }
 
-// #[test]
-// fn flexbox_macro_test() {
-//     assert_eq!(
-//         crate::declarations!(crate::flexbox!(
-//             width (100 px) .. (200 px),
-//             height .. (200 px),
-//             top (100 px) | (50 px),
-//             horizontal (15 px) | (0),
-//             column,
-//         )),
-//         crate::declarations!(
-//             crate::Property::BoxSizing(crate::BoxSizing::BorderBox),
-//             crate::Property::FlexShrink(crate::FlexShrink::Zero),
-//             crate::Property::Display(crate::Display::Flex),
-//             crate::Property::MinWidth(crate::DimensionExtremity::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(100.) }))),
-//             crate::Property::MaxWidth(crate::DimensionExtremity::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(200.) }))),
-//             crate::Property::MaxHeight(crate::DimensionExtremity::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(200.) }))),
-//             crate::Property::MarginTop(crate::Margin::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(100.) }))),
-//             crate::Property::PaddingTop(crate::Padding::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(50.) }))),
-//             crate::Property::MarginLeft(crate::Margin::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(15.) }))),
-//             crate::Property::PaddingLeft(crate::Padding::None),
-//             crate::Property::MarginRight(crate::Margin::Some(crate::units::Unit::Px(unsafe { crate::units::F32::unchecked_new(15.) }))),
-//             crate::Property::PaddingRight(crate::Padding::None),
-//             crate::Property::FlexDirection(crate::FlexDirection::Column),
-//         ),
-//     );
-// }
-
-/*
-should define some kind of a Box struct that impls AppendProperty
-this construct will completely handle the element's position and size (i.e. the bits that influence position of other elements on the page)
-split line by line until comma and run through some kind of __box_line! macro
-box!(
-	width (250 px) .. (500 px), // syntax is either <min .. max> or just <value>
-	height (100 px),
-	absolute,
-	top (25 px) .. (10 px) | (15 px), // top .. margin-top | padding-top
-	right (0 .. 5px |1 px solid black| 10 px), // right .. margin-right | border-right-width border-right-style border-right-color | padding-right
-)
-
-so i.e., the above structure would be equaivalent to:
-{
-	// these are appended automatically as it's what you'd almost certainly want
-	box-sizing: border-box;
-	flex-shrink: 0;
-
-	min-width: 250px;
-	max-width: 500px;
-	height: 100px;
-	position: absolute;
-	top: 25px;
-	margin-top: 10px;
-	padding-top: 15px;
-	right: 0;
-	margin-right: 5px;
-	border-right-width: 1px;
-	border-right-style: solid;
-	border-right-color: black;
-	padding-right: 10px;
-}
-*/
-
 // update @ 22 november - dumb as shit, definitely no the way to go
 // idk if this is not dumb, looks pretty dumb to me
 // will also need to account for 1 or 2 elements, should be ez to amend tts
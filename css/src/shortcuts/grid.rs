@@ -0,0 +1,120 @@
+/*
+css::grid!(
+	column,
+	// [*-gap] | [grid-template-*] >> [grid-auto-*]
+	columns (32 px) | (1 fr) (50 px) repeat(5, (1 fr)) >> (1 fr),
+	rows >> (1 fr),
+	areas "header header" "sidebar main",
+)
+*/
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __grid_auto {
+	($kind:ident, $(($($unit:tt)+))+) => {
+		$crate::paste::item!{$crate::Property::[<GridAuto $kind>]($crate::GridAuto::Some(vec![$($crate::unit!($($unit)+)),+]))}
+	};
+	($kind:ident, $($unit:tt)+) => {
+		$crate::paste::item!{$crate::Property::[<GridAuto $kind>]($crate::GridAuto::Some(vec![$crate::unit!($($unit)+)]))}
+	};
+}
+
+// splits a `columns`/`rows` line into the track list before `>>` and the implicit track sizes after it
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __grid_axis {
+	($acc:expr, $kind:ident, $template_macro:ident, template = (), rest = ()) => {};
+	($acc:expr, $kind:ident, $template_macro:ident, template = ($($template:tt)+), rest = ()) => {
+		$acc.push($crate::$template_macro!($($template)+));
+	};
+	($acc:expr, $kind:ident, $template_macro:ident, template = ($($template:tt)*), rest = (>> $($auto:tt)+)) => {
+		$crate::__grid_axis!($acc, $kind, $template_macro, template = ($($template)*), rest = ());
+		$acc.push($crate::__grid_auto!($kind, $($auto)+));
+	};
+	($acc:expr, $kind:ident, $template_macro:ident, template = ($($template:tt)*), rest = ($token:tt $($rest:tt)*)) => {
+		$crate::__grid_axis!($acc, $kind, $template_macro, template = ($($template)* $token), rest = ($($rest)*));
+	};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __grid_line {
+	($acc:expr, row) => { $acc.push($crate::grid_auto_flow!(row)) };
+	($acc:expr, column) => { $acc.push($crate::grid_auto_flow!(column)) };
+	($acc:expr, row dense) => { $acc.push($crate::grid_auto_flow!(row dense)) };
+	($acc:expr, column dense) => { $acc.push($crate::grid_auto_flow!(column dense)) };
+
+	($acc:expr, areas $($row:literal)+) => { $acc.push($crate::grid_template_areas!($($row),+)) };
+
+	($acc:expr, columns ($($gap:tt)+) | $($rest:tt)*) => {
+		$acc.push($crate::column_gap!($($gap)+));
+		$crate::__grid_axis!($acc, Columns, grid_template_columns, template = (), rest = ($($rest)*));
+	};
+	($acc:expr, columns $($rest:tt)*) => { $crate::__grid_axis!($acc, Columns, grid_template_columns, template = (), rest = ($($rest)*)); };
+	($acc:expr, rows ($($gap:tt)+) | $($rest:tt)*) => {
+		$acc.push($crate::row_gap!($($gap)+));
+		$crate::__grid_axis!($acc, Rows, grid_template_rows, template = (), rest = ($($rest)*));
+	};
+	($acc:expr, rows $($rest:tt)*) => { $crate::__grid_axis!($acc, Rows, grid_template_rows, template = (), rest = ($($rest)*)); };
+
+	($acc:expr, [$($inner:tt)*]) => { $crate::__grid_line!($acc, $($inner)*) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __grid_inner {
+	(
+		lines = ($($line:tt)*),
+		current_line = (),
+		rest = (),
+	) => {{
+		let mut acc = vec![$crate::display!(grid)];
+		$($crate::__grid_line!(acc, $line);)*
+		acc
+	}};
+	(
+		lines = ($($lines:tt)*),
+		current_line = ($($current_line:tt)*),
+		rest = (, $($rest:tt)*),
+	) => {
+		$crate::__grid_inner!{
+			lines = ($($lines)* [$($current_line)*]),
+			current_line = (),
+			rest = ($($rest)*),
+		}
+	};
+	(
+		lines = ($($lines:tt)*),
+		current_line = ($($current_line:tt)+),
+		rest = (),
+	) => {
+		$crate::__grid_inner!{
+			lines = ($($lines)* [$($current_line)*]),
+			current_line = (),
+			rest = (),
+		}
+	};
+	(
+		lines = ($($lines:tt)*),
+		current_line = ($($current_line:tt)*),
+		rest = ($current:tt $($rest:tt)*),
+	) => {
+		$crate::__grid_inner!{
+			lines = ($($lines)*),
+			current_line = ($($current_line)* $current),
+			rest = ($($rest)*),
+		}
+	};
+}
+
+/// `display: grid` along with the grid's gaps, explicit and implicit tracks, auto flow and areas
+#[macro_export]
+macro_rules! grid {
+	($($tt:tt)*) => {
+		$crate::__grid_inner!{
+			lines = (),
+			current_line = (),
+			rest = ($($tt)*),
+		}
+	};
+}
@@ -22,13 +22,13 @@ fn initial_inherit_unset() {
 	assert_eq!(css::grid_auto_flow!(inherit).to_string(), "grid-auto-flow:inherit;");
 	assert_eq!(css::grid_auto_flow!(unset).to_string(), "grid-auto-flow:unset;");
 
-	// assert_eq!(css::grid_template_columns!(initial).to_string(), "grid-template-columns:initial;");
-	// assert_eq!(css::grid_template_columns!(inherit).to_string(), "grid-template-columns:inherit;");
-	// assert_eq!(css::grid_template_columns!(unset).to_string(), "grid-template-columns:unset;");
-	//
-	// assert_eq!(css::grid_template_rows!(initial).to_string(), "grid-template-rows:initial;");
-	// assert_eq!(css::grid_template_rows!(inherit).to_string(), "grid-template-rows:inherit;");
-	// assert_eq!(css::grid_template_rows!(unset).to_string(), "grid-template-rows:unset;");
+	assert_eq!(css::grid_template_columns!(initial).to_string(), "grid-template-columns:initial;");
+	assert_eq!(css::grid_template_columns!(inherit).to_string(), "grid-template-columns:inherit;");
+	assert_eq!(css::grid_template_columns!(unset).to_string(), "grid-template-columns:unset;");
+
+	assert_eq!(css::grid_template_rows!(initial).to_string(), "grid-template-rows:initial;");
+	assert_eq!(css::grid_template_rows!(inherit).to_string(), "grid-template-rows:inherit;");
+	assert_eq!(css::grid_template_rows!(unset).to_string(), "grid-template-rows:unset;");
 
 	assert_eq!(css::margin_bottom!(initial).to_string(), "margin-bottom:initial;");
 	assert_eq!(css::margin_bottom!(inherit).to_string(), "margin-bottom:inherit;");
@@ -74,3 +74,127 @@ fn initial_inherit_unset() {
 	assert_eq!(css::z_index!(inherit).to_string(), "z-index:inherit;");
 	assert_eq!(css::z_index!(unset).to_string(), "z-index:unset;");
 }
+
+#[test]
+fn logical_properties() {
+	assert_eq!(css::margin_inline_start!(initial).to_string(), "margin-inline-start:initial;");
+	assert_eq!(css::margin_block_end!(auto).to_string(), "margin-block-end:auto;");
+	assert_eq!(css::padding_inline_end!(unset).to_string(), "padding-inline-end:unset;");
+	assert_eq!(css::inset_inline_start!(inherit).to_string(), "inset-inline-start:inherit;");
+	assert_eq!(css::inline_size!(auto).to_string(), "inline-size:auto;");
+	assert_eq!(css::max_block_size!(none).to_string(), "max-block-size:none;");
+	assert_eq!(css::border_inline_start_style!(solid).to_string(), "border-inline-start-style:solid;");
+	assert_eq!(css::border_block_end_width!(initial).to_string(), "border-block-end-width:initial;");
+	assert_eq!(css::border_start_start_radius!(initial).to_string(), "border-start-start-radius:initial;");
+
+	assert_eq!(css::margin_inline!(auto), vec![css::margin_inline_start!(auto), css::margin_inline_end!(auto)]);
+	assert_eq!(css::padding_block!(unset), vec![css::padding_block_start!(unset), css::padding_block_end!(unset)]);
+	assert_eq!(css::inset_inline!(initial), vec![css::inset_inline_start!(initial), css::inset_inline_end!(initial)]);
+	assert_eq!(css::inset!(initial), vec![css::top!(initial), css::right!(initial), css::bottom!(initial), css::left!(initial)]);
+}
+
+#[test]
+fn grid() {
+	assert_eq!(
+		css::grid_template_columns!([full-start] minmax((100 px), (1 fr)) [main] fit-content(300 px), repeat(auto-fill, minmax(min-content, (200 px)))).to_string(),
+		"grid-template-columns:[full-start] minmax(100px,1fr) [main] fit-content(300px) repeat(auto-fill, minmax(min-content,200px));",
+	);
+	assert_eq!(css::grid_template_rows!((50 px), auto, (1 fr)).to_string(), "grid-template-rows:50px auto 1fr;");
+	assert_eq!(css::grid_template_areas!("header header", "sidebar main").to_string(), r#"grid-template-areas:"header header" "sidebar main";"#);
+	assert_eq!(css::grid_area!(sidebar).to_string(), "grid-area:sidebar;");
+	assert_eq!(css::place_items!(center).to_string(), "place-items:center;");
+	assert_eq!(css::place_content!(space-between, flex-start).to_string(), "place-content:space-between flex-start;");
+	assert_eq!(css::place_self!(end, start).to_string(), "place-self:end start;");
+	assert_eq!(css::gap!(10 px).to_string(), "gap:10px;");
+	assert_eq!(css::gap!((10 px), (20 px)).to_string(), "gap:10px 20px;");
+	assert_eq!(css::PlaceItems { align: css::AlignItems::Center, justify: css::JustifyItems::Center }.expand(), vec![css::align_items!(center), css::justify_items!(center)]);
+
+	assert_eq!(css::grid!(
+		column dense,
+		columns (32 px) | (1 fr) (50 px) repeat(5, (1 fr)) >> 1 fr,
+		rows >> (1 fr) (2 fr),
+		areas "a b" "c d",
+	), vec![
+		css::display!(grid),
+		css::grid_auto_flow!(column dense),
+		css::column_gap!(32 px),
+		css::grid_template_columns!((1 fr) (50 px) repeat(5, (1 fr))),
+		css::Property::GridAutoColumns(css::GridAuto::Some(vec![css::unit!(1 fr)])),
+		css::Property::GridAutoRows(css::GridAuto::Some(vec![css::unit!(1 fr), css::unit!(2 fr)])),
+		css::grid_template_areas!("a b", "c d"),
+	]);
+
+	let template = css::GridTemplateShorthand::Areas {
+		rows: vec![("header header".into(), Some(css::GridTemplateValue::Unit(css::unit!(50 px)))), ("sidebar main".into(), None)],
+		columns: vec![css::GridTemplateValue::Unit(css::unit!(200 px)), css::GridTemplateValue::Unit(css::unit!(1 fr))],
+	};
+	assert_eq!(template.to_string(), r#"grid-template:"header header" 50px "sidebar main" / 200px 1fr;"#);
+	assert_eq!(template.expand(), vec![
+		css::grid_template_rows!((50 px) auto),
+		css::grid_template_columns!((200 px) (1 fr)),
+		css::grid_template_areas!("header header", "sidebar main"),
+	]);
+
+	let grid = css::Grid::AutoRows { dense: true, rows: vec![css::unit!(100 px)], columns: vec![css::GridTemplateValue::Repeat(css::GridRepeat { number: css::GridRepeatNumber::Some(3), values: vec![css::GridTemplateValue::Unit(css::unit!(1 fr))] })] };
+	assert_eq!(grid.to_string(), "grid:auto-flow dense 100px / repeat(3, 1fr);");
+	assert_eq!(grid.expand(), vec![
+		css::grid_template_rows!(none),
+		css::grid_template_columns!(repeat(3, (1 fr))),
+		css::grid_template_areas!(none),
+		css::Property::GridAutoRows(css::GridAuto::Some(vec![css::unit!(100 px)])),
+		css::Property::GridAutoColumns(css::GridAuto::Initial),
+		css::grid_auto_flow!(row dense),
+	]);
+}
+
+#[test]
+fn box_layout() {
+	let layout = css::BoxLayout::flex()
+		.size(css::unit!(100 px))
+		.max_width(css::DimensionExtremity::None)
+		.margin_horizontal(css::Margin::Auto)
+		.padding(css::unit!(8 px))
+		.flex_direction(css::FlexDirection::Column)
+		.align_items(css::AlignItems::Center);
+
+	assert_eq!(css::properties![layout.clone()], css::properties![
+		css::box_sizing!(border-box),
+		css::flex_shrink!(0),
+		css::display!(flex),
+		css::width!(100 px),
+		css::height!(100 px),
+		css::max_width!(none),
+		css::margin_right!(auto),
+		css::margin_left!(auto),
+		css::padding_top!(8 px),
+		css::padding_right!(8 px),
+		css::padding_bottom!(8 px),
+		css::padding_left!(8 px),
+		css::flex_direction!(column),
+		css::align_items!(center),
+	]);
+
+	let overridden = layout.merge(css::BoxLayout::new().width(css::unit!(50 px)).with(css::opacity!(0.5)));
+	assert_eq!(overridden.width, Some(css::Dimension::Some(css::unit!(50 px))));
+	assert_eq!(overridden.height, Some(css::Dimension::Some(css::unit!(100 px))));
+	assert_eq!(overridden.other, vec![css::opacity!(0.5)]);
+
+	assert_eq!(
+		css::flexbox!(
+			width (100 px) .. (200 px),
+			height .. (200 px),
+			top (100 px) | (50 px),
+			horizontal (15 px) | (0),
+			column,
+		),
+		css::BoxLayout::flex()
+			.min_width(css::unit!(100 px))
+			.max_width(css::unit!(200 px))
+			.max_height(css::unit!(200 px))
+			.margin_top(css::unit!(100 px))
+			.padding_top(css::unit!(50 px))
+			.margin_horizontal(css::unit!(15 px))
+			.padding_horizontal(css::Unit::Zero)
+			.flex_direction(css::FlexDirection::Column),
+	);
+}
@@ -59,6 +59,7 @@ pub fn derive_event_target(input: proc_macro::TokenStream) -> proc_macro::TokenS
 			::hobo::EventTarget,
 			trait EventTarget {
 				fn event_handlers(&self) -> ::std::cell::RefMut<::std::vec::Vec<::hobo::EventHandler>>;
+				fn delegator(&self) -> ::std::option::Option<::hobo::Delegator>;
 			}
 		},
 		_ => {
@@ -67,6 +68,7 @@ pub fn derive_event_target(input: proc_macro::TokenStream) -> proc_macro::TokenS
 			(quote! {
 				impl #impl_generics ::hobo::EventTarget for #name #ty_generics #where_clause {
 					fn event_handlers(&self) -> ::std::cell::RefMut<::std::vec::Vec<::hobo::EventHandler>> { self.element.event_handlers() }
+					fn delegator(&self) -> ::std::option::Option<::hobo::Delegator> { self.element.delegator() }
 				}
 			}).into()
 		},
@@ -158,3 +160,49 @@ pub fn derive_raw_element(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 		}
 	}).into()
 }
+
+/// Generates a `{Name}Lenses` trait with a `hobo::state::Lens` accessor per field,
+/// implemented for `State<Name>` as well as lenses focused on `Name`
+#[proc_macro_derive(Lenses)]
+pub fn derive_lenses(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	let name = &input.ident;
+	let vis = &input.vis;
+	let trait_name = quote::format_ident!("{}Lenses", name);
+	let fields = match &input.data {
+		syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(syn::FieldsNamed { named, .. }), .. }) => named,
+		_ => panic!("Lenses can only be derived for structs with named fields"),
+	};
+	if let Some(lifetime) = input.generics.lifetimes().next() {
+		return syn::Error::new_spanned(lifetime, "Lenses can't be derived for structs with lifetime parameters, states have to be 'static").to_compile_error().into();
+	}
+
+	// states and lenses only hold 'static values
+	let mut generics = input.generics.clone();
+	let params = generics.type_params().map(|x| x.ident.clone()).collect::<Vec<_>>();
+	let where_clause = generics.make_where_clause();
+	for param in params {
+		where_clause.predicates.push(syn::parse_quote!(#param: 'static));
+	}
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	let mut blanket_generics = generics.clone();
+	blanket_generics.params.push(syn::parse_quote!(__HoboFocus: ::hobo::state::Focus<#name #ty_generics>));
+	let (blanket_impl_generics, _, _) = blanket_generics.split_for_impl();
+
+	let accessors = fields.iter().map(|field| {
+		let ident = &field.ident;
+		let ty = &field.ty;
+		quote! {
+			fn #ident(&self) -> ::hobo::state::Lens<Self::Root, #ty> { self.focus(|x| &x.#ident, |x| &mut x.#ident) }
+		}
+	});
+
+	(quote! {
+		#vis trait #trait_name #impl_generics: ::hobo::state::Focus<#name #ty_generics> #where_clause {
+			#(#accessors)*
+		}
+
+		impl #blanket_impl_generics #trait_name #ty_generics for __HoboFocus #where_clause {}
+	}).into()
+}
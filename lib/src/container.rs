@@ -1,4 +1,4 @@
-use crate::Element;
+use crate::{state::{State, StateVec}, Element, KeyedList};
 use std::{cell::RefCell, rc::Rc};
 
 /// Trait for all hobo components that can have children
@@ -26,6 +26,16 @@ pub trait Container: Element {
 	fn child_ref(self, child: &(impl crate::Element + 'static)) -> Self where Self: Sized { self.add_child_ref(child); self }
 	fn with_children<Item>(mut self, children: impl IntoIterator<Item = Item>) -> Self where Self: Sized, Item: crate::Element + 'static { self.add_children(children); self }
 	fn with_children_refs<'a, Item>(self, children: impl IntoIterator<Item = &'a Item>) -> Self where Self: Sized, Item: crate::Element + 'static { self.add_children_refs(children); self }
+
+	/// Render a child for each item of `list`, identified by `key`, and keep them in sync with it, see `KeyedList`
+	fn keyed_children<T, K, E>(self, list: &StateVec<T>, key: impl Fn(&T) -> K + 'static, render: impl FnMut(&State<T>) -> E + 'static) -> KeyedList<Self, T, K> where
+		Self: Sized,
+		T: Clone + 'static,
+		K: PartialEq + 'static,
+		E: crate::Element + 'static,
+	{
+		KeyedList::new(self, list, key, render)
+	}
 }
 
 impl<T: Container> Container for Rc<RefCell<T>> {
@@ -0,0 +1,52 @@
+//! typed helpers for the `DataTransfer` payloads of drag and drop events
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[extend::ext(pub, name = DataTransferExt)]
+impl web_sys::DataTransfer {
+	/// Store a Rust value serialized as JSON under `format`
+	fn set_value<T: Serialize>(&self, format: &str, value: &T) {
+		let data = serde_json::to_string(value).expect("can't serialize data transfer value");
+		self.set_data(format, &data).expect("can't set data transfer data");
+	}
+
+	/// Read back a value stored with `set_value`
+	/// browsers only expose the data on `drop`, during the other drag events only `has_format` can be relied on
+	fn value<T: DeserializeOwned>(&self, format: &str) -> Option<T> {
+		let data = self.get_data(format).ok().filter(|x| !x.is_empty())?;
+		serde_json::from_str(&data).ok()
+	}
+
+	fn has_format(&self, format: &str) -> bool {
+		let types = self.types();
+		(0..types.length()).any(|i| types.get(i).as_string().as_deref() == Some(format))
+	}
+
+	/// Dropped files, if there are any
+	fn file_list(&self) -> Vec<web_sys::File> {
+		let files = if let Some(x) = self.files() { x } else { return Vec::new(); };
+		(0..files.length()).filter_map(|i| files.get(i)).collect()
+	}
+}
+
+#[extend::ext(pub, name = DragEventExt)]
+impl web_sys::DragEvent {
+	/// Same as `DataTransferExt::set_value` on the event's `DataTransfer`
+	fn set_value<T: Serialize>(&self, format: &str, value: &T) {
+		if let Some(x) = self.data_transfer() { x.set_value(format, value); }
+	}
+
+	/// Same as `DataTransferExt::value` on the event's `DataTransfer`
+	fn value<T: DeserializeOwned>(&self, format: &str) -> Option<T> { self.data_transfer()?.value(format) }
+
+	/// Same as `DataTransferExt::has_format` on the event's `DataTransfer`
+	fn has_format(&self, format: &str) -> bool {
+		if let Some(x) = self.data_transfer() { x.has_format(format) } else { false }
+	}
+
+	/// Same as `DataTransferExt::file_list` on the event's `DataTransfer`
+	fn file_list(&self) -> Vec<web_sys::File> { self.data_transfer().map(|x| x.file_list()).unwrap_or_default() }
+
+	/// Allow dropping onto the element, needs to be called from both `on_drag_enter` and `on_drag_over`
+	fn accept_drop(&self) { self.prevent_default(); }
+}
@@ -0,0 +1,142 @@
+//! opt-in event delegation, for when attaching a closure to every one of thousands of elements is too costly
+
+use crate::{prelude::*, Element, EventHandler, EventTarget};
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, rc::Rc, sync::atomic::{AtomicU64, Ordering}};
+
+type DelegatedFn = Rc<RefCell<dyn FnMut(web_sys::Event)>>;
+
+/// These don't bubble, so they get a regular listener on the element itself instead
+const NON_BUBBLING: &[&str] = &["mouseenter", "mouseleave", "pointerenter", "pointerleave", "focus", "blur", "scroll", "load", "error", "invalid"];
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_id() -> u64 { NEXT_ID.fetch_add(1, Ordering::Relaxed) }
+
+fn delegate_id(element: &web_sys::Element) -> Option<u64> {
+	element.get_attribute(web_str::data_hobo_delegate()).and_then(|x| x.parse().ok())
+}
+
+struct Listener {
+	_root_handler: EventHandler,
+	handlers: HashMap<u64, Vec<(u64, DelegatedFn)>>,
+}
+
+struct Inner {
+	root: web_sys::EventTarget,
+	root_element: Option<web_sys::Element>,
+	listeners: RefCell<HashMap<String, Listener>>,
+}
+
+impl Inner {
+	fn dispatch(&self, event: &web_sys::Event) {
+		let node = if let Some(x) = event.target().and_then(|x| x.dyn_into::<web_sys::Node>().ok()) { x } else { return; };
+		let mut current = match node.dyn_into::<web_sys::Element>() { Ok(x) => Some(x), Err(node) => node.parent_element() };
+		let name = event.type_();
+		while let Some(element) = current {
+			if let Some(id) = delegate_id(&element) {
+				let handlers = self.listeners.borrow().get(&name)
+					.and_then(|x| x.handlers.get(&id))
+					.map(|x| x.iter().map(|(_, f)| Rc::clone(f)).collect::<Vec<_>>())
+					.unwrap_or_default();
+				for handler in handlers {
+					if let Ok(mut f) = handler.try_borrow_mut() { (*f)(event.clone()); }
+				}
+				if event.cancel_bubble() { return; }
+			}
+			if self.root_element.as_ref() == Some(&element) { return; }
+			current = element.parent_element();
+		}
+	}
+}
+
+/// A single listener per event type on `root`, dispatching to the handlers of registered elements
+/// by walking up from `event.target`, as if every element had its own listener
+/// handlers still receive the raw event, so `current_target` is the root rather than the element the handler was registered on
+/// `stop_propagation` stops the walk at the element whose handler called it
+#[derive(Clone)]
+pub struct Delegator(Rc<Inner>);
+
+impl Delegator {
+	pub fn new(root: &web_sys::Element) -> Self {
+		Self(Rc::new(Inner { root: root.clone().into(), root_element: Some(root.clone()), listeners: Default::default() }))
+	}
+
+	/// Delegate to the document, which catches events from anywhere on the page
+	pub fn document() -> Self {
+		Self(Rc::new(Inner { root: crate::dom().into(), root_element: None, listeners: Default::default() }))
+	}
+
+	/// Wrap an element so that all of its `EventTarget` methods go through this delegator
+	pub fn delegate<T: EventTarget>(&self, element: T) -> Delegated<T> { Delegated { element, delegator: self.clone() } }
+
+	/// Register `f` for events named `name` happening on `element` or its descendants
+	/// the returned handler unregisters it when dropped
+	pub fn add<E: JsCast + 'static>(&self, element: &web_sys::Element, name: &str, mut f: impl FnMut(E) + 'static) -> EventHandler {
+		if NON_BUBBLING.contains(&name) { return element.on(name, f); }
+
+		let element_id = delegate_id(element).unwrap_or_else(|| {
+			let id = next_id();
+			element.set_attribute(web_str::data_hobo_delegate(), &id.to_string()).expect("can't set attribute");
+			id
+		});
+		let handler_id = next_id();
+		let handler: DelegatedFn = Rc::new(RefCell::new(move |event: web_sys::Event| f(event.unchecked_into())));
+
+		self.0.listeners.borrow_mut()
+			.entry(name.to_owned())
+			.or_insert_with(|| {
+				let inner = Rc::downgrade(&self.0);
+				let root_handler = self.0.root.on(name, move |event: web_sys::Event| {
+					if let Some(inner) = inner.upgrade() { inner.dispatch(&event); }
+				});
+				Listener { _root_handler: root_handler, handlers: HashMap::new() }
+			})
+			.handlers.entry(element_id).or_default().push((handler_id, handler));
+
+		let inner = Rc::downgrade(&self.0);
+		let name = name.to_owned();
+		EventHandler::from_cleanup(move || {
+			let inner = if let Some(x) = inner.upgrade() { x } else { return; };
+			// dropping closures can drop other delegated handlers, so nothing is dropped while `listeners` is borrowed
+			let mut removed = Vec::new();
+			let mut removed_listener = None;
+			{
+				let mut listeners = inner.listeners.borrow_mut();
+				let listener = if let Some(x) = listeners.get_mut(&name) { x } else { return; };
+				if let Some(handlers) = listener.handlers.get_mut(&element_id) {
+					removed.extend(handlers.iter().position(|(id, _)| *id == handler_id).map(|i| handlers.remove(i)));
+					if handlers.is_empty() { listener.handlers.remove(&element_id); }
+				}
+				if listener.handlers.is_empty() { removed_listener = listeners.remove(&name); }
+			}
+			drop(removed);
+			drop(removed_listener);
+		})
+	}
+}
+
+/// An element whose event handlers are registered with a `Delegator` instead of attaching listeners of their own
+pub struct Delegated<T> {
+	pub element: T,
+	delegator: Delegator,
+}
+
+impl<T> std::ops::Deref for Delegated<T> {
+	type Target = T;
+
+	fn deref(&self) -> &T { &self.element }
+}
+
+impl<T> std::ops::DerefMut for Delegated<T> {
+	fn deref_mut(&mut self) -> &mut T { &mut self.element }
+}
+
+impl<T: Element> Element for Delegated<T> {
+	fn element(&self) -> Cow<'_, web_sys::Element> { self.element.element() }
+	fn classes(&self) -> Rc<RefCell<HashMap<u64, css::Style>>> { self.element.classes() }
+}
+
+impl<T: EventTarget> EventTarget for Delegated<T> {
+	fn event_handlers(&self) -> std::cell::RefMut<'_, Vec<EventHandler>> { self.element.event_handlers() }
+	fn delegator(&self) -> Option<Delegator> { Some(self.delegator.clone()) }
+}
@@ -34,6 +34,24 @@ pub trait Element {
 		self.element().remove_attribute(&key.into()).expect("can't set attribute");
 	}
 
+	/// Route all further events of the pointer to this element until it's released, e.g. for dragging
+	/// fails with a `NotFoundError` if the pointer isn't active anymore, e.g. after `pointerup` or `pointercancel`
+	fn set_pointer_capture(&self, pointer_id: i32) -> Result<(), JsValue> { self.element().set_pointer_capture(pointer_id) }
+	/// Fails the same way as `set_pointer_capture`
+	fn release_pointer_capture(&self, pointer_id: i32) -> Result<(), JsValue> { self.element().release_pointer_capture(pointer_id) }
+	fn has_pointer_capture(&self, pointer_id: i32) -> bool { self.element().has_pointer_capture(pointer_id) }
+
+	/// Resolves with the `animationend` or `animationcancel` event of this element, e.g. to await an exit animation before replacing it in a `Slot`
+	/// it never resolves if no animation gets to run, so start the animation before awaiting
+	fn animation_finished(&self) -> crate::events::NextEvent {
+		crate::events::NextEvent::new(&self.element(), &[web_str::animationend(), web_str::animationcancel()])
+	}
+
+	/// Same as `animation_finished`, but for `transitionend` or `transitioncancel`
+	fn transition_finished(&self) -> crate::events::NextEvent {
+		crate::events::NextEvent::new(&self.element(), &[web_str::transitionend(), web_str::transitioncancel()])
+	}
+
 	/// Set a tagged class, which means that if a different tag is used - a new style will be applied
 	/// alternatively, if a tag that's been used previously is used again - that style is overwritten
 	fn set_class_tagged<'a, Tag: Hash + 'static>(&self, tag: Tag, style: impl Into<Cow<'a, css::Style>>) where Self: Sized + 'static {
@@ -125,7 +143,8 @@ impl web_sys::Element {
 	}
 
 	fn set_style<'a>(&self, style: impl Into<Cow<'a, [css::Property]>>) {
-		let _ = self.set_attribute(web_str::style(), &style.into().iter().map(std::string::ToString::to_string).collect::<String>());
+		let style = STYLE_STORAGE.with(|style_storage| style_storage.prefixer.borrow().prefix_properties(&style.into()));
+		let _ = self.set_attribute(web_str::style(), &style.iter().map(std::string::ToString::to_string).collect::<String>());
 	}
 
 	fn remove_style(&self) { let _ = self.remove_attribute(web_str::style()); }
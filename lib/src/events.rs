@@ -1,92 +1,70 @@
 //! everything that has to do with HTML event handling
 
-use crate::{prelude::*, Element};
-use std::{cell::RefCell, mem::MaybeUninit, rc::Rc};
-
-pub enum EventHandler {
-	MouseEvent(Closure<dyn FnMut(web_sys::MouseEvent) + 'static>),
-	KeyboardEvent(Closure<dyn FnMut(web_sys::KeyboardEvent) + 'static>),
-	Event(Closure<dyn FnMut(web_sys::Event) + 'static>),
-	FocusEvent(Closure<dyn FnMut(web_sys::FocusEvent) + 'static>),
-
-	// AnimationEvent
-	// AnimationPlaybackEvent
-	// DeviceMotionEvent
-	// DeviceOrientationEvent
-	// DeviceProximityEvent
-	// DragEvent
-	// ErrorEvent
-	// ExtendableEvent
-	// ExtendableMessageEvent
-	// FetchEvent
-	// AudioProcessingEvent
-	// FontFaceSetLoadEvent
-	// GamepadAxisMoveEvent
-	// GamepadButtonEvent
-	// GamepadEvent
-	// GpuUncapturedErrorEvent
-	// HashChangeEvent
-	// IdbVersionChangeEvent
-	// ImageCaptureErrorEvent
-	// InputEvent
-	// BeforeUnloadEvent
-	// MediaEncryptedEvent
-	// MediaKeyError
-	// MediaKeyMessageEvent
-	// MediaQueryListEvent
-	// MediaRecorderErrorEvent
-	// MediaStreamEvent
-	// MediaStreamTrackEvent
-	// MessageEvent
-	// MidiConnectionEvent
-	// MidiMessageEvent
-	// BlobEvent
-	// MouseScrollEvent
-	// MutationEvent
-	// NotificationEvent
-	// OfflineAudioCompletionEvent
-	// PageTransitionEvent
-	// PaymentMethodChangeEvent
-	// PaymentRequestUpdateEvent
-	// PointerEvent
-	// PopStateEvent
-	// ClipboardEvent
-	// PopupBlockedEvent
-	// PresentationConnectionAvailableEvent
-	// PresentationConnectionCloseEvent
-	// ProgressEvent
-	// PromiseRejectionEvent
-	// PushEvent
-	// RtcDataChannelEvent
-	// RtcPeerConnectionIceEvent
-	// RtcTrackEvent
-	// RtcdtmfToneChangeEvent
-	// CloseEvent
-	// ScrollAreaEvent
-	// SecurityPolicyViolationEvent
-	// SpeechRecognitionError
-	// SpeechRecognitionEvent
-	// SpeechSynthesisErrorEvent
-	// SpeechSynthesisEvent
-	// StorageEvent
-	// TcpServerSocketEvent
-	// TcpSocketErrorEvent
-	// TcpSocketEvent
-	// CompositionEvent
-	// TimeEvent
-	// TouchEvent
-	// TrackEvent
-	// TransitionEvent
-	// UiEvent
-	// UserProximityEvent
-	// WebGlContextEvent
-	// WheelEvent
-	// XrInputSourceEvent
-	// XrInputSourcesChangeEvent
-	// CustomEvent
-	// XrReferenceSpaceEvent
-	// XrSessionEvent
-	// DeviceLightEvent
+use crate::{prelude::*, Delegator, Element};
+use wasm_bindgen::JsCast;
+use std::{cell::RefCell, future::Future, mem::MaybeUninit, pin::Pin, rc::Rc, task::{Context, Poll, Waker}};
+
+/// Options for `add_event_listener`, see https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#Parameters
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ListenerOptions {
+	/// The handler promises to never call `prevent_default`, which lets the browser scroll without waiting on it for `wheel` and `touch*` events
+	pub passive: bool,
+	/// Handle the event on the way down to the target rather than on the way up
+	pub capture: bool,
+	/// Remove the listener after it's been called once
+	pub once: bool,
+}
+
+impl ListenerOptions {
+	pub const PASSIVE: Self = Self { passive: true, capture: false, once: false };
+	pub const CAPTURE: Self = Self { passive: false, capture: true, once: false };
+	pub const ONCE: Self = Self { passive: false, capture: false, once: true };
+
+	fn to_js(self) -> web_sys::AddEventListenerOptions {
+		let mut options = web_sys::AddEventListenerOptions::new();
+		options.passive(self.passive).capture(self.capture).once(self.once);
+		options
+	}
+}
+
+/// An attached event listener, it is removed and its closure is freed when the handler is dropped or cancelled
+#[must_use = "the listener is removed as soon as the handler is dropped"]
+pub struct EventHandler(HandlerKind);
+
+enum HandlerKind {
+	Listener {
+		target: web_sys::EventTarget,
+		name: String,
+		capture: bool,
+		closure: Closure<dyn FnMut(web_sys::Event) + 'static>,
+	},
+	Cleanup(Option<Box<dyn FnOnce()>>),
+}
+
+impl EventHandler {
+	/// Attach `f` to `target`, the event is cast to `E` unchecked, so it must match what the browser dispatches for `name`
+	pub fn new<E: JsCast + 'static>(target: &web_sys::EventTarget, name: &str, options: ListenerOptions, mut f: impl FnMut(E) + 'static) -> Self {
+		let closure = Closure::wrap(Box::new(move |event: web_sys::Event| f(event.unchecked_into())) as Box<dyn FnMut(web_sys::Event) + 'static>);
+		target.add_event_listener_with_callback_and_add_event_listener_options(name, closure.as_ref().unchecked_ref(), &options.to_js()).expect("can't add event listener");
+		Self(HandlerKind::Listener { target: target.clone(), name: name.to_owned(), capture: options.capture, closure })
+	}
+
+	/// A handler that runs `f` instead of removing a listener, for handlers registered somewhere other than the DOM
+	pub(crate) fn from_cleanup(f: impl FnOnce() + 'static) -> Self { Self(HandlerKind::Cleanup(Some(Box::new(f)))) }
+
+	/// Remove the listener right away, same as dropping the handler
+	pub fn cancel(self) {}
+}
+
+impl Drop for EventHandler {
+	fn drop(&mut self) {
+		match &mut self.0 {
+			HandlerKind::Listener { target, name, capture, closure } => {
+				let _ = target.remove_event_listener_with_callback_and_bool(name, closure.as_ref().unchecked_ref(), *capture);
+			},
+			HandlerKind::Cleanup(f) => if let Some(f) = f.take() { f() },
+		}
+	}
 }
 
 pub type EventHandlers = RefCell<Vec<EventHandler>>;
@@ -94,15 +72,98 @@ pub type EventHandlers = RefCell<Vec<EventHandler>>;
 macro_rules! generate_events {
 	($($event_kind:ident, $name:ident, $f:ident);+$(;)*) => {paste::item!{
 		/// Trait for all hobo elements that can handle various browser events
+		/// `add_*` methods return the handler, which has to be kept around for the listener to stay attached,
+		/// while the chaining methods store it in the element, so it lives as long as the element does
+		/// handlers attached by chaining can't be removed or replaced, so handlers that come and go should be `add_*`ed
 		pub trait EventTarget: Element {
 			fn event_handlers(&self) -> std::cell::RefMut<Vec<EventHandler>>;
+
+			/// The `Delegator` that handlers are registered with instead of attaching listeners to the element, see `Delegated`
+			fn delegator(&self) -> Option<Delegator> { None }
+
+			/// Capture the pointer on `pointerdown`, so move/up events keep coming to this element even after the pointer leaves it
+			fn add_capture_pointer_on_down(&self) -> EventHandler {
+				self.add_on_pointer_down(|event| {
+					let target = if let Some(x) = event.current_target().and_then(|x| x.dyn_into::<web_sys::Element>().ok()) { x } else { return; };
+					// the pointer can be gone already, which isn't worth panicking over
+					let _ = target.set_pointer_capture(event.pointer_id());
+				})
+			}
+
+			/// Chaining alternative to `add_capture_pointer_on_down`
+			fn capture_pointer_on_down(self) -> Self where Self: Sized {
+				let handler = self.add_capture_pointer_on_down();
+				self.event_handlers().push(handler);
+				self
+			}
+
+			/// Handle an event by name, for events that don't have a dedicated method
+			/// the event is cast to `E` unchecked, so it must match what the browser dispatches for `name`
+			fn add_on<E: JsCast + 'static>(&self, name: &str, f: impl FnMut(E) + 'static) -> EventHandler {
+				match self.delegator() {
+					Some(delegator) => delegator.add(&self.element(), name, f),
+					None => self.element().on(name, f),
+				}
+			}
+			/// `add_on` with listener options, which always attaches a listener to the element itself
+			/// delegated handlers share one listener, so passing options opts out of delegation
+			fn add_on_with<E: JsCast + 'static>(&self, name: &str, options: ListenerOptions, f: impl FnMut(E) + 'static) -> EventHandler { self.element().on_with(name, options, f) }
+
+			fn add_on_mut<E: JsCast + 'static, T: 'static>(&self, this: &Rc<MaybeUninit<RefCell<T>>>, name: &str, mut f: impl FnMut(&mut T, E) + 'static) -> EventHandler {
+				let weak = Rc::downgrade(this);
+				self.add_on(name, move |event| {
+					let strong = if let Some(x) = weak.upgrade() { x } else { return; };
+					let inited: Rc<RefCell<T>> = unsafe { Rc::from_raw((&*Rc::into_raw(strong)).as_ptr()) };
+					f(&mut inited.borrow_mut(), event);
+				})
+			}
+
+			/// Chaining alternative to `add_on`
+			fn on<E: JsCast + 'static>(self, name: &str, f: impl FnMut(E) + 'static) -> Self where Self: Sized {
+				let handler = self.add_on(name, f);
+				self.event_handlers().push(handler);
+				self
+			}
+
+			/// Chaining alternative to `add_on_with`
+			fn on_with<E: JsCast + 'static>(self, name: &str, options: ListenerOptions, f: impl FnMut(E) + 'static) -> Self where Self: Sized {
+				let handler = self.add_on_with(name, options, f);
+				self.event_handlers().push(handler);
+				self
+			}
+
+			/// Chaining alternative to `add_on_mut`
+			fn on_mut<E: JsCast + 'static, T: 'static>(self, this: &Rc<MaybeUninit<RefCell<T>>>, name: &str, f: impl FnMut(&mut T, E) + 'static) -> Self where Self: Sized {
+				let handler = self.add_on_mut(this, name, f);
+				self.event_handlers().push(handler);
+				self
+			}
+
+			/// Handle a `CustomEvent` dispatched on this element or bubbled up from its descendants
+			fn add_on_custom<T: 'static>(&self, event: &CustomEvent<T>, f: impl FnMut(&T, web_sys::CustomEvent) + 'static) -> EventHandler {
+				self.element().on_custom(event, f)
+			}
+
+			/// Chaining alternative to `add_on_custom`
+			fn on_custom<T: 'static>(self, event: &CustomEvent<T>, f: impl FnMut(&T, web_sys::CustomEvent) + 'static) -> Self where Self: Sized {
+				let handler = self.add_on_custom(event, f);
+				self.event_handlers().push(handler);
+				self
+			}
+
+			/// Dispatch a `CustomEvent` from this element, see `CustomEvent::dispatch`
+			fn emit<T: 'static>(&self, event: &CustomEvent<T>, payload: T) -> bool { event.dispatch(&self.element(), payload) }
 			$(
-				fn [<add_ $f>](&self, f: impl FnMut(web_sys::$event_kind) + 'static) {
-					let handler = self.element().$f(f);
-					self.event_handlers().push(handler);
+				fn [<add_ $f>](&self, f: impl FnMut(web_sys::$event_kind) + 'static) -> EventHandler {
+					match self.delegator() {
+						Some(delegator) => delegator.add(&self.element(), web_str::$name(), f),
+						None => self.element().$f(f),
+					}
 				}
+				/// Passing options opts out of delegation, same as `add_on_with`
+				fn [<add_ $f _with>](&self, options: ListenerOptions, f: impl FnMut(web_sys::$event_kind) + 'static) -> EventHandler { self.element().[<$f _with>](options, f) }
 
-				fn [<add_ $f _mut>]<T: 'static>(&self, this: &Rc<MaybeUninit<RefCell<T>>>, mut f: impl FnMut(&mut T, web_sys::$event_kind) + 'static) {
+				fn [<add_ $f _mut>]<T: 'static>(&self, this: &Rc<MaybeUninit<RefCell<T>>>, mut f: impl FnMut(&mut T, web_sys::$event_kind) + 'static) -> EventHandler {
 					let weak = Rc::downgrade(this);
 					self.[<add_ $f>](move |event| {
 						let strong = if let Some(x) = weak.upgrade() { x } else { return; };
@@ -112,12 +173,20 @@ macro_rules! generate_events {
 				}
 
 				fn $f(self, f: impl FnMut(web_sys::$event_kind) + 'static) -> Self where Self: Sized {
-					self.[<add_ $f>](f);
+					let handler = self.[<add_ $f>](f);
+					self.event_handlers().push(handler);
+					self
+				}
+
+				fn [<$f _with>](self, options: ListenerOptions, f: impl FnMut(web_sys::$event_kind) + 'static) -> Self where Self: Sized {
+					let handler = self.[<add_ $f _with>](options, f);
+					self.event_handlers().push(handler);
 					self
 				}
 
 				fn [<$f _mut>]<T: 'static>(self, this: &Rc<MaybeUninit<RefCell<T>>>, f: impl FnMut(&mut T, web_sys::$event_kind) + 'static) -> Self where Self: Sized {
-					self.[<add_ $f _mut>](this, f);
+					let handler = self.[<add_ $f _mut>](this, f);
+					self.event_handlers().push(handler);
 					self
 				}
 			)+
@@ -125,13 +194,21 @@ macro_rules! generate_events {
 
 		/// Extension event for raw web_sys elements for convenient attaching of event handlers
 		#[extend::ext(pub, name = [<RawEventTarget>])]
-		impl web_sys::EventTarget {$(
-			#[must_use]
-			fn $f(&self, f: impl FnMut(web_sys::$event_kind) + 'static) -> EventHandler {
-				let handler = Closure::wrap(Box::new(f) as Box<dyn FnMut(web_sys::$event_kind) + 'static>);
-				self.add_event_listener_with_callback(web_str::$name(), handler.as_ref().unchecked_ref()).expect("can't add event listener");
-				EventHandler::$event_kind(handler)
+		impl web_sys::EventTarget {
+			/// Attach a handler for an event by name, the event is cast to `E` unchecked
+			fn on<E: JsCast + 'static>(&self, name: &str, f: impl FnMut(E) + 'static) -> EventHandler { EventHandler::new(self, name, ListenerOptions::default(), f) }
+			fn on_with<E: JsCast + 'static>(&self, name: &str, options: ListenerOptions, f: impl FnMut(E) + 'static) -> EventHandler { EventHandler::new(self, name, options, f) }
+
+			/// Attach a handler for a `CustomEvent`, events of the same name that don't carry a `T` are ignored
+			fn on_custom<T: 'static>(&self, event: &CustomEvent<T>, mut f: impl FnMut(&T, web_sys::CustomEvent) + 'static) -> EventHandler {
+				self.on(event.name, move |event: web_sys::CustomEvent| {
+					let payload = if let Some(x) = CustomEvent::<T>::payload(&event) { x } else { return; };
+					f(&payload, event);
+				})
 			}
+		$(
+			fn $f(&self, f: impl FnMut(web_sys::$event_kind) + 'static) -> EventHandler { EventHandler::new(self, web_str::$name(), ListenerOptions::default(), f) }
+			fn [<$f _with>](&self, options: ListenerOptions, f: impl FnMut(web_sys::$event_kind) + 'static) -> EventHandler { EventHandler::new(self, web_str::$name(), options, f) }
 		)+}
 	}};
 }
@@ -153,14 +230,208 @@ generate_events! {
 	Event,         scroll,      on_scroll;
 	FocusEvent,    blur,        on_blur;
 	FocusEvent,    focus,       on_focus;
+	PointerEvent,  pointerdown,        on_pointer_down;
+	PointerEvent,  pointermove,        on_pointer_move;
+	PointerEvent,  pointerup,          on_pointer_up;
+	PointerEvent,  pointercancel,      on_pointer_cancel;
+	PointerEvent,  pointerenter,       on_pointer_enter;
+	PointerEvent,  pointerleave,       on_pointer_leave;
+	PointerEvent,  gotpointercapture,  on_got_pointer_capture;
+	PointerEvent,  lostpointercapture, on_lost_pointer_capture;
+	TouchEvent,    touchstart,         on_touch_start;
+	TouchEvent,    touchmove,          on_touch_move;
+	TouchEvent,    touchend,           on_touch_end;
+	TouchEvent,    touchcancel,        on_touch_cancel;
+	WheelEvent,    wheel,              on_wheel;
+	DragEvent,     dragstart,          on_drag_start;
+	DragEvent,     drag,               on_drag;
+	DragEvent,     dragend,            on_drag_end;
+	DragEvent,     dragenter,          on_drag_enter;
+	DragEvent,     dragover,           on_drag_over;
+	DragEvent,     dragleave,          on_drag_leave;
+	DragEvent,     drop,               on_drop;
+	InputEvent,    input,              on_input;
+	InputEvent,    beforeinput,        on_before_input;
+	CompositionEvent, compositionstart,  on_composition_start;
+	CompositionEvent, compositionupdate, on_composition_update;
+	CompositionEvent, compositionend,    on_composition_end;
+	ClipboardEvent, copy,              on_copy;
+	ClipboardEvent, cut,               on_cut;
+	ClipboardEvent, paste,             on_paste;
+	Event,         select,             on_select;
+	Event,         submit,             on_submit;
+	Event,         reset,              on_reset;
+	Event,         invalid,            on_invalid;
+	AnimationEvent, animationstart,    on_animation_start;
+	AnimationEvent, animationend,      on_animation_end;
+	AnimationEvent, animationiteration, on_animation_iteration;
+	AnimationEvent, animationcancel,   on_animation_cancel;
+	TransitionEvent, transitionrun,    on_transition_run;
+	TransitionEvent, transitionstart,  on_transition_start;
+	TransitionEvent, transitionend,    on_transition_end;
+	TransitionEvent, transitioncancel, on_transition_cancel;
+}
+
+macro_rules! generate_global_events {
+	($($event_kind:ident, $target:ident, $name:ident, $f:ident);+$(;)*) => {paste::item!{
+		/// Listening to `window` and `document` events for as long as an element is alive
+		/// the chaining methods store handlers in `event_handlers`, so they're removed when the element is dropped
+		pub trait GlobalEventTarget: EventTarget {$(
+			fn [<add_ $f>](&self, f: impl FnMut(web_sys::$event_kind) + 'static) -> EventHandler {
+				EventHandler::new(&$target(), web_str::$name(), ListenerOptions::default(), f)
+			}
+
+			fn [<add_ $f _mut>]<T: 'static>(&self, this: &Rc<MaybeUninit<RefCell<T>>>, mut f: impl FnMut(&mut T, web_sys::$event_kind) + 'static) -> EventHandler {
+				let weak = Rc::downgrade(this);
+				self.[<add_ $f>](move |event| {
+					let strong = if let Some(x) = weak.upgrade() { x } else { return; };
+					let inited: Rc<RefCell<T>> = unsafe { Rc::from_raw((&*Rc::into_raw(strong)).as_ptr()) };
+					f(&mut inited.borrow_mut(), event);
+				})
+			}
+
+			fn $f(self, f: impl FnMut(web_sys::$event_kind) + 'static) -> Self where Self: Sized {
+				let handler = self.[<add_ $f>](f);
+				self.event_handlers().push(handler);
+				self
+			}
+
+			fn [<$f _mut>]<T: 'static>(self, this: &Rc<MaybeUninit<RefCell<T>>>, f: impl FnMut(&mut T, web_sys::$event_kind) + 'static) -> Self where Self: Sized {
+				let handler = self.[<add_ $f _mut>](this, f);
+				self.event_handlers().push(handler);
+				self
+			}
+		)+}
+
+		impl<T: EventTarget> GlobalEventTarget for T {}
+	}};
+}
+
+fn window() -> web_sys::EventTarget { web_sys::window().expect("no window").into() }
+fn document() -> web_sys::EventTarget { web_sys::window().expect("no window").document().expect("no document").into() }
+
+generate_global_events! {
+	Event,             window,   resize,           on_window_resize;
+	PopStateEvent,     window,   popstate,         on_pop_state;
+	HashChangeEvent,   window,   hashchange,       on_hash_change;
+	Event,             window,   online,           on_online;
+	Event,             window,   offline,          on_offline;
+	StorageEvent,      window,   storage,          on_storage;
+	BeforeUnloadEvent, window,   beforeunload,     on_before_unload;
+	Event,             document, visibilitychange, on_visibility_change;
+	KeyboardEvent,     document, keydown,          on_global_key_down;
 }
 
 impl<T: EventTarget> EventTarget for RefCell<T> {
 	fn event_handlers(&self) -> std::cell::RefMut<Vec<EventHandler>> {
 		unsafe { self.try_borrow_unguarded() }.expect("rc is mutably borrowed").event_handlers()
 	}
+
+	fn delegator(&self) -> Option<Delegator> { self.borrow().delegator() }
 }
 
 impl<T: EventTarget> EventTarget for Rc<T> {
 	fn event_handlers(&self) -> std::cell::RefMut<Vec<EventHandler>> { T::event_handlers(&self) }
+	fn delegator(&self) -> Option<Delegator> { T::delegator(self) }
+}
+
+type FiredEvent = Rc<RefCell<(Option<web_sys::Event>, Option<Waker>)>>;
+
+/// Future that resolves with the first of the given events fired on the target itself, events bubbling up from descendants are ignored
+/// the listeners are removed once it's dropped
+pub struct NextEvent {
+	target: web_sys::EventTarget,
+	names: Vec<&'static str>,
+	fired: FiredEvent,
+	handler: Closure<dyn FnMut(web_sys::Event) + 'static>,
+}
+
+impl NextEvent {
+	pub fn new(target: &web_sys::EventTarget, names: &[&'static str]) -> Self {
+		let fired: FiredEvent = Rc::new(RefCell::new((None, None)));
+		let handler = {
+			let (target, fired) = (target.clone(), Rc::clone(&fired));
+			Closure::wrap(Box::new(move |event: web_sys::Event| {
+				if event.target().as_ref() != Some(&target) { return; }
+				let waker = {
+					let mut fired = fired.borrow_mut();
+					if fired.0.is_some() { return; }
+					fired.0 = Some(event);
+					fired.1.take()
+				};
+				if let Some(waker) = waker { waker.wake(); }
+			}) as Box<dyn FnMut(web_sys::Event) + 'static>)
+		};
+		for name in names {
+			target.add_event_listener_with_callback(name, handler.as_ref().unchecked_ref()).expect("can't add event listener");
+		}
+		Self { target: target.clone(), names: names.to_vec(), fired, handler }
+	}
+}
+
+impl Future for NextEvent {
+	type Output = web_sys::Event;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut fired = self.fired.borrow_mut();
+		if let Some(event) = fired.0.take() { return Poll::Ready(event); }
+		fired.1 = Some(cx.waker().clone());
+		Poll::Pending
+	}
+}
+
+impl Drop for NextEvent {
+	fn drop(&mut self) {
+		for name in &self.names {
+			let _ = self.target.remove_event_listener_with_callback(name, self.handler.as_ref().unchecked_ref());
+		}
+	}
+}
+
+type CustomEventPayloads = (u32, std::collections::HashMap<u32, Rc<dyn std::any::Any>>);
+
+thread_local! {
+	static CUSTOM_EVENT_PAYLOADS: RefCell<CustomEventPayloads> = Default::default();
+}
+
+/// A bubbling DOM event carrying a Rust payload, usually declared as a `const` and shared between the emitter and the listeners
+/// ```rust,ignore
+/// const ITEM_SELECTED: CustomEvent<usize> = CustomEvent::new("item-selected");
+///
+/// list.on_custom(&ITEM_SELECTED, |index, _| log::info!("selected {}", index));
+/// item.emit(&ITEM_SELECTED, 5);
+/// ```
+pub struct CustomEvent<T> {
+	pub name: &'static str,
+	_payload: std::marker::PhantomData<fn(T)>,
+}
+
+impl<T> CustomEvent<T> {
+	pub const fn new(name: &'static str) -> Self { Self { name, _payload: std::marker::PhantomData } }
+}
+
+impl<T: 'static> CustomEvent<T> {
+	/// Dispatch the event on `target`, it bubbles up through its ancestors and can be cancelled
+	/// the payload only lives for the duration of the dispatch, which is synchronous
+	/// returns `false` if any handler called `prevent_default`
+	pub fn dispatch(&self, target: &web_sys::EventTarget, payload: T) -> bool {
+		let id = CUSTOM_EVENT_PAYLOADS.with(|payloads| {
+			let (next_id, payloads) = &mut *payloads.borrow_mut();
+			let id = *next_id;
+			*next_id = next_id.wrapping_add(1);
+			payloads.insert(id, Rc::new(payload));
+			id
+		});
+		let event = web_sys::CustomEvent::new(self.name).expect("can't create custom event");
+		event.init_custom_event_with_can_bubble_and_cancelable_and_detail(self.name, true, true, &JsValue::from(id));
+		let res = target.dispatch_event(&event).expect("can't dispatch event");
+		CUSTOM_EVENT_PAYLOADS.with(|payloads| payloads.borrow_mut().1.remove(&id));
+		res
+	}
+
+	fn payload(event: &web_sys::CustomEvent) -> Option<Rc<T>> {
+		let id = event.detail().as_f64()? as u32;
+		let payload = CUSTOM_EVENT_PAYLOADS.with(|payloads| payloads.borrow().1.get(&id).cloned())?;
+		payload.downcast().ok()
+	}
 }
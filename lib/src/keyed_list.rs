@@ -0,0 +1,115 @@
+use crate::{
+	prelude::*,
+	state::{State, StateVec, Subscription, VecDiff},
+	Element, EventHandler, EventTarget, Delegator,
+};
+use std::{
+	borrow::Cow,
+	cell::RefCell,
+	collections::HashMap,
+	ops::{Deref, DerefMut},
+	rc::Rc,
+};
+
+struct Entry<T, K> {
+	key: K,
+	state: State<T>,
+	element: Box<dyn Element>,
+}
+
+type Entries<T, K> = Rc<RefCell<Vec<Entry<T, K>>>>;
+
+/// An element with a child for each item of a `StateVec`, kept up to date by only touching the items that changed
+/// every child gets its own `State` with the item, so updating an item without changing its key
+/// keeps the child as it is and just updates that state, while a different key renders a new child
+/// see `Container::keyed_children`
+pub struct KeyedList<C, T, K> {
+	pub container: C,
+	entries: Entries<T, K>,
+	_subscription: Subscription,
+}
+
+fn entry<T: Clone + 'static, K, E: Element + 'static>(value: &T, key: &dyn Fn(&T) -> K, render: &mut dyn FnMut(&State<T>) -> E) -> Entry<T, K> {
+	let state = State::new(value.clone());
+	let element = Box::new(render(&state));
+	Entry { key: key(value), state, element }
+}
+
+/// Insert a child's node so that it ends up at `index` among the nodes of `entries`
+fn insert_node<T, K>(parent: &web_sys::Element, entries: &[Entry<T, K>], index: usize, element: &dyn Element) {
+	let next: Option<web_sys::Node> = entries.get(index).map(|x| x.element.element().into_owned().into());
+	parent.insert_before(&element.element(), next.as_ref()).expect("can't insert child");
+}
+
+impl<C: Element, T: Clone + 'static, K: PartialEq + 'static> KeyedList<C, T, K> {
+	pub fn new<E: Element + 'static>(container: C, list: &StateVec<T>, key: impl Fn(&T) -> K + 'static, mut render: impl FnMut(&State<T>) -> E + 'static) -> Self {
+		let parent = container.element().into_owned();
+		let entries = list.view().iter().map(|value| entry(value, &key, &mut render)).collect::<Vec<_>>();
+		for entry in &entries {
+			parent.append_child(&entry.element.element()).expect("can't append child");
+		}
+		let entries = Rc::new(RefCell::new(entries));
+
+		let weak = Rc::downgrade(&entries);
+		let subscription = list.subscribe_diffs(move |diff| {
+			let entries = if let Some(x) = weak.upgrade() { x } else { return; };
+			match diff {
+				VecDiff::Insert { index, value } => {
+					let new = entry(value, &key, &mut render);
+					insert_node(&parent, &entries.borrow(), *index, &*new.element);
+					entries.borrow_mut().insert(*index, new);
+				},
+				VecDiff::Remove { index } => {
+					let old = entries.borrow_mut().remove(*index);
+					old.element.element().remove();
+				},
+				VecDiff::Move { from, to } => {
+					let moved = entries.borrow_mut().remove(*from);
+					insert_node(&parent, &entries.borrow(), *to, &*moved.element);
+					entries.borrow_mut().insert(*to, moved);
+				},
+				VecDiff::Update { index, value } => {
+					let same_key = entries.borrow()[*index].key == key(value);
+					if same_key {
+						let state = entries.borrow()[*index].state.clone();
+						*state.update() = value.clone();
+					} else {
+						let new = entry(value, &key, &mut render);
+						parent.insert_before(&new.element.element(), Some(&entries.borrow()[*index].element.element())).expect("can't insert child");
+						let old = std::mem::replace(&mut entries.borrow_mut()[*index], new);
+						old.element.element().remove();
+					}
+				},
+				VecDiff::Clear => {
+					let old = std::mem::take(&mut *entries.borrow_mut());
+					for entry in old { entry.element.element().remove(); }
+				},
+			}
+		});
+
+		Self { container, entries, _subscription: subscription }
+	}
+
+	/// The states handed out to the children, in the same order as the items
+	pub fn item_states(&self) -> Vec<State<T>> { self.entries.borrow().iter().map(|x| x.state.clone()).collect() }
+}
+
+impl<C, T, K> Deref for KeyedList<C, T, K> {
+	type Target = C;
+
+	fn deref(&self) -> &Self::Target { &self.container }
+}
+
+impl<C, T, K> DerefMut for KeyedList<C, T, K> {
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.container }
+}
+
+impl<C: Element, T, K> Element for KeyedList<C, T, K> {
+	fn element(&self) -> Cow<'_, web_sys::Element> { self.container.element() }
+	fn classes(&self) -> Rc<RefCell<HashMap<u64, css::Style>>> { self.container.classes() }
+}
+
+impl<C: EventTarget, T, K> EventTarget for KeyedList<C, T, K> {
+	fn event_handlers(&self) -> std::cell::RefMut<'_, Vec<EventHandler>> { self.container.event_handlers() }
+	fn delegator(&self) -> Option<Delegator> { self.container.delegator() }
+}
@@ -1,11 +1,16 @@
 mod basic_element;
 mod container;
 pub mod create;
+mod data_transfer;
+mod delegation;
 mod element;
 mod enclose;
 pub mod events;
+mod keyed_list;
 pub mod prelude;
+mod rate_limit;
 mod replaceable;
+pub mod shortcuts;
 mod slot;
 pub mod state;
 mod style_storage;
@@ -15,14 +20,19 @@ pub mod web_str;
 pub use basic_element::{BasicElement, RawElement};
 pub use container::*;
 pub use create::components as cmp;
+pub use data_transfer::{DataTransferExt, DragEventExt};
+pub use delegation::{Delegated, Delegator};
 pub use hobo_css as css;
 pub use element::Element;
 #[doc(inline)]
 pub use events::*;
 pub use hobo_derive::*;
+pub use keyed_list::KeyedList;
 #[doc(hidden)]
 pub use paste;
+pub use rate_limit::{debounce, debounce_with, throttle, throttle_raf, throttle_with, RateLimit};
 pub use replaceable::*;
+pub use shortcuts::{Shortcut, ShortcutTarget};
 pub use slot::*;
 pub use web_sys;
 
@@ -32,6 +42,12 @@ thread_local! {
 	static STYLE_STORAGE: style_storage::StyleStorage = Default::default();
 }
 
+/// Set which browsers vendor prefixes are generated for
+/// only affects styles that haven't been inserted yet, so it's best called before creating any elements
+pub fn set_prefixer(prefixer: css::Prefixer) {
+	STYLE_STORAGE.with(move |style_storage| *style_storage.prefixer.borrow_mut() = prefixer);
+}
+
 /// Trait for hobo components with textual contents
 pub trait SetText<T>: RawElement<RawElementType = T>
 where
@@ -1,7 +1,7 @@
 #[doc(hidden)]
 pub use crate::{
 	element::{HashToClassString as _, RawSetClass as _},
-	Container as _, Element as _, EventTarget as _, RawElement as _, Replaceable as _, SetText as _, Slot,
+	Container as _, Element as _, EventTarget as _, GlobalEventTarget as _, RawElement as _, Replaceable as _, SetText as _, ShortcutTarget as _, Slot,
 	ToClassStr as _,
 };
 #[doc(hidden)] pub use std::convert::TryInto as _;
@@ -9,7 +9,7 @@ pub use crate::{
 
 pub use crate::{
 	state,
-	web_str, RawEventTarget as _,
+	web_str, RawEventTarget as _, DataTransferExt as _, DragEventExt as _,
 };
 pub use crate::css::{self, AppendProperty, F32, F32Ext as _};
 pub use wasm_bindgen::prelude::*;
@@ -0,0 +1,133 @@
+//! rate limiting adapters for event handlers, e.g. `.on_input(debounce(300, f))` or `.on_scroll(throttle_raf(f))`
+//! pending calls are cancelled once the handler is dropped, which happens along with the element it's attached to
+
+use crate::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// Whether to call at the start and/or the end of a wait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+	pub leading: bool,
+	pub trailing: bool,
+}
+
+impl RateLimit {
+	pub const LEADING: Self = Self { leading: true, trailing: false };
+	pub const TRAILING: Self = Self { leading: false, trailing: true };
+	pub const BOTH: Self = Self { leading: true, trailing: true };
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Clock {
+	Timeout(i32),
+	AnimationFrame,
+}
+
+impl Clock {
+	fn schedule(self, f: &js_sys::Function) -> i32 {
+		let window = web_sys::window().expect("no window");
+		match self {
+			Self::Timeout(ms) => window.set_timeout_with_callback_and_timeout_and_arguments_0(f, ms).expect("can't set timeout"),
+			Self::AnimationFrame => window.request_animation_frame(f).expect("can't request animation frame"),
+		}
+	}
+
+	fn cancel(self, handle: i32) {
+		let window = web_sys::window().expect("no window");
+		match self {
+			Self::Timeout(_) => window.clear_timeout_with_handle(handle),
+			Self::AnimationFrame => { let _ = window.cancel_animation_frame(handle); },
+		}
+	}
+}
+
+struct Inner<E> {
+	pending: Option<E>,
+	handle: Option<i32>,
+	/// the `tick` closure of the owning `Limiter`, used to reschedule it
+	tick: Option<js_sys::Function>,
+}
+
+impl<E> Inner<E> {
+	fn schedule(&mut self, clock: Clock) {
+		if let Some(tick) = &self.tick { self.handle = Some(clock.schedule(tick)); }
+	}
+}
+
+/// Owned by the adapted handler, cancels whatever is scheduled when the handler goes away
+struct Limiter<E> {
+	inner: Rc<RefCell<Inner<E>>>,
+	clock: Clock,
+	_tick: Closure<dyn FnMut() + 'static>,
+}
+
+impl<E> Drop for Limiter<E> {
+	fn drop(&mut self) {
+		if let Some(handle) = self.inner.borrow_mut().handle.take() { self.clock.cancel(handle); }
+	}
+}
+
+fn call<E>(f: &RefCell<dyn FnMut(E)>, event: E) {
+	if let Ok(mut f) = f.try_borrow_mut() { (*f)(event); }
+}
+
+fn rate_limit<E: 'static>(clock: Clock, options: RateLimit, debounce: bool, f: impl FnMut(E) + 'static) -> impl FnMut(E) + 'static {
+	let f: Rc<RefCell<dyn FnMut(E)>> = Rc::new(RefCell::new(f));
+	let inner = Rc::new(RefCell::new(Inner { pending: None, handle: None, tick: None }));
+	let tick = Closure::wrap(Box::new({
+		let (inner, f) = (Rc::downgrade(&inner), Rc::clone(&f));
+		move || {
+			let inner = if let Some(x) = inner.upgrade() { x } else { return; };
+			let event = {
+				let mut inner = inner.borrow_mut();
+				inner.handle = None;
+				let event = inner.pending.take().filter(|_| options.trailing);
+				// a trailing call starts a new throttling window
+				if event.is_some() && !debounce { inner.schedule(clock); }
+				event
+			};
+			if let Some(event) = event { call(&f, event); }
+		}
+	}) as Box<dyn FnMut() + 'static>);
+	inner.borrow_mut().tick = Some(tick.as_ref().unchecked_ref::<js_sys::Function>().clone());
+	let limiter = Limiter { inner, clock, _tick: tick };
+
+	move |event| {
+		let leading = {
+			let mut inner = limiter.inner.borrow_mut();
+			let idle = inner.handle.is_none();
+			if debounce {
+				if let Some(handle) = inner.handle.take() { clock.cancel(handle); }
+			}
+			if inner.handle.is_none() { inner.schedule(clock); }
+			if idle && options.leading {
+				inner.pending = None;
+				Some(event)
+			} else {
+				inner.pending = Some(event);
+				None
+			}
+		};
+		if let Some(event) = leading { call(&f, event); }
+	}
+}
+
+/// Call `f` once there have been no calls for `ms` milliseconds, with the latest event
+pub fn debounce<E: 'static>(ms: i32, f: impl FnMut(E) + 'static) -> impl FnMut(E) + 'static { debounce_with(ms, RateLimit::TRAILING, f) }
+
+/// `debounce` that can also call right away at the start of a burst of calls
+pub fn debounce_with<E: 'static>(ms: i32, options: RateLimit, f: impl FnMut(E) + 'static) -> impl FnMut(E) + 'static {
+	rate_limit(Clock::Timeout(ms), options, true, f)
+}
+
+/// Call `f` at most once every `ms` milliseconds, right away and then with the latest event at the end of each window
+pub fn throttle<E: 'static>(ms: i32, f: impl FnMut(E) + 'static) -> impl FnMut(E) + 'static { throttle_with(ms, RateLimit::BOTH, f) }
+
+pub fn throttle_with<E: 'static>(ms: i32, options: RateLimit, f: impl FnMut(E) + 'static) -> impl FnMut(E) + 'static {
+	rate_limit(Clock::Timeout(ms), options, false, f)
+}
+
+/// Call `f` at most once per animation frame with the latest event, for handlers that update layout like `scroll` or `pointermove`
+pub fn throttle_raf<E: 'static>(f: impl FnMut(E) + 'static) -> impl FnMut(E) + 'static {
+	rate_limit(Clock::AnimationFrame, RateLimit::TRAILING, false, f)
+}
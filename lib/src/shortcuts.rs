@@ -0,0 +1,354 @@
+//! keyboard shortcuts, chords like `Ctrl+Shift+K` and sequences like `g i`
+
+#[cfg(test)]
+mod tests;
+
+use crate::{prelude::*, state::State, EventHandler, EventTarget};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// Keys of a sequence have to be pressed within this many milliseconds of each other
+const SEQUENCE_TIMEOUT: f64 = 1000.;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutError {
+	Parse(String),
+	/// The shortcut is the same as, or a prefix of, or prefixed by a shortcut already registered in the same scope
+	Conflict { shortcut: String, existing: String },
+}
+
+impl fmt::Display for ShortcutError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Parse(x) => write!(f, "can't parse shortcut: {}", x),
+			Self::Conflict { shortcut, existing } => write!(f, "shortcut {} conflicts with {}", shortcut, existing),
+		}
+	}
+}
+
+impl std::error::Error for ShortcutError {}
+
+/// A key together with the modifiers that have to be held
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chord {
+	pub ctrl: bool,
+	pub alt: bool,
+	pub shift: bool,
+	pub meta: bool,
+	/// lowercase `KeyboardEvent.key`
+	pub key: String,
+}
+
+impl Chord {
+	/// `Mod` is `Meta` on Apple platforms and `Ctrl` everywhere else
+	pub fn parse(s: &str, apple: bool) -> Result<Self, ShortcutError> {
+		let parts = s.split('+').collect::<Vec<_>>();
+		let (modifiers, key) = if parts.len() >= 2 && parts[parts.len() - 1].is_empty() && parts[parts.len() - 2].is_empty() {
+			(&parts[..parts.len() - 2], "+")
+		} else {
+			(&parts[..parts.len() - 1], parts[parts.len() - 1])
+		};
+		if key.is_empty() { return Err(ShortcutError::Parse(format!("missing key in {:?}", s))); }
+
+		let mut chord = Self { ctrl: false, alt: false, shift: false, meta: false, key: normalize_key(key) };
+		for modifier in modifiers {
+			match modifier.to_lowercase().as_str() {
+				"ctrl" | "control" => chord.ctrl = true,
+				"alt" | "option" => chord.alt = true,
+				"shift" => chord.shift = true,
+				"meta" | "cmd" | "command" | "super" | "win" => chord.meta = true,
+				"mod" => if apple { chord.meta = true } else { chord.ctrl = true },
+				x => return Err(ShortcutError::Parse(format!("unknown modifier {:?} in {:?}", x, s))),
+			}
+		}
+		Ok(chord)
+	}
+
+	fn matches(&self, pressed: &Pressed) -> bool {
+		// symbols like `?` already need shift on most layouts, so it isn't spelled out in the shortcut
+		let mut chars = self.key.chars();
+		let symbol = matches!((chars.next(), chars.next()), (Some(x), None) if !x.is_alphanumeric() && x != ' ');
+		self.ctrl == pressed.ctrl && self.alt == pressed.alt && self.meta == pressed.meta && (self.shift == pressed.shift || symbol) &&
+			// `code` keeps working when a modifier changes what `key` produces, like alt on macs
+			(self.key == pressed.key || code_for_key(&self.key).as_ref() == Some(&pressed.code))
+	}
+
+	fn has_modifier(&self) -> bool { self.ctrl || self.alt || self.meta }
+}
+
+impl fmt::Display for Chord {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.ctrl { f.write_str("Ctrl+")?; }
+		if self.alt { f.write_str("Alt+")?; }
+		if self.shift { f.write_str("Shift+")?; }
+		if self.meta { f.write_str("Meta+")?; }
+		match self.key.as_str() {
+			" " => f.write_str("Space"),
+			"+" => f.write_str("Plus"),
+			x => f.write_str(x),
+		}
+	}
+}
+
+fn normalize_key(key: &str) -> String {
+	match key.to_lowercase().as_str() {
+		"esc" => "escape".to_owned(),
+		"space" | "spacebar" => " ".to_owned(),
+		"plus" => "+".to_owned(),
+		"up" | "down" | "left" | "right" => format!("arrow{}", key.to_lowercase()),
+		"del" => "delete".to_owned(),
+		"return" => "enter".to_owned(),
+		x => x.to_owned(),
+	}
+}
+
+fn code_for_key(key: &str) -> Option<String> {
+	let mut chars = key.chars();
+	match (chars.next(), chars.next()) {
+		(Some(x), None) if x.is_ascii_alphabetic() => Some(format!("key{}", x)),
+		(Some(x), None) if x.is_ascii_digit() => Some(format!("digit{}", x)),
+		_ => None,
+	}
+}
+
+/// Chords to be pressed one after another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence(pub Vec<Chord>);
+
+impl Sequence {
+	/// Whitespace separated chords, see `Chord::parse`
+	pub fn parse(s: &str, apple: bool) -> Result<Self, ShortcutError> {
+		let chords = s.split_whitespace().map(|x| Chord::parse(x, apple)).collect::<Result<Vec<_>, _>>()?;
+		if chords.is_empty() { return Err(ShortcutError::Parse("empty shortcut".to_owned())); }
+		Ok(Self(chords))
+	}
+
+	fn is_prefix_of(&self, other: &Self) -> bool { self.0.len() <= other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| a == b) }
+
+	fn starts_with(&self, pressed: &[Pressed]) -> bool {
+		pressed.len() <= self.0.len() && self.0.iter().zip(pressed).all(|(chord, pressed)| chord.matches(pressed))
+	}
+}
+
+impl fmt::Display for Sequence {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, chord) in self.0.iter().enumerate() {
+			if i != 0 { f.write_str(" ")?; }
+			write!(f, "{}", chord)?;
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scope {
+	Global,
+	/// the element that the shortcut is added to, resolved in `ShortcutTarget::add_shortcut`
+	Owner,
+	Element(web_sys::Element),
+}
+
+/// A shortcut to be registered through `ShortcutTarget`
+/// ```rust,ignore
+/// element.shortcut(Shortcut::new("Mod+S").unwrap().enabled_by(&can_save), move |_| save())
+/// ```
+pub struct Shortcut {
+	sequence: Sequence,
+	scope: Scope,
+	enabled: Option<State<bool>>,
+}
+
+impl Shortcut {
+	pub fn new(keys: &str) -> Result<Self, ShortcutError> {
+		Ok(Self { sequence: Sequence::parse(keys, is_apple())?, scope: Scope::Global, enabled: None })
+	}
+
+	/// Only active while focus is within the element the shortcut is added to
+	pub fn scoped(mut self) -> Self { self.scope = Scope::Owner; self }
+
+	/// Only active while focus is within `element`
+	pub fn scoped_to(mut self, element: &web_sys::Element) -> Self { self.scope = Scope::Element(element.clone()); self }
+
+	/// Only active while `state` is `true`
+	pub fn enabled_by(mut self, state: &State<bool>) -> Self { self.enabled = Some(state.clone()); self }
+}
+
+fn is_apple() -> bool {
+	let platform = web_sys::window().expect("no window").navigator().platform().unwrap_or_default();
+	["Mac", "iPhone", "iPad", "iPod"].iter().any(|x| platform.contains(x))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pressed {
+	ctrl: bool,
+	alt: bool,
+	shift: bool,
+	meta: bool,
+	key: String,
+	code: String,
+}
+
+type ShortcutFn = Rc<RefCell<dyn FnMut(web_sys::KeyboardEvent)>>;
+
+struct Binding {
+	id: u64,
+	sequence: Sequence,
+	scope: Scope,
+	enabled: Option<State<bool>>,
+	handler: Option<ShortcutFn>,
+}
+
+impl Binding {
+	fn is_enabled(&self) -> bool {
+		if let Some(x) = &self.enabled { *x.view() } else { true }
+	}
+}
+
+#[derive(Default)]
+struct Registry {
+	bindings: Vec<Binding>,
+	pending: Vec<Pressed>,
+	last_time: f64,
+	next_id: u64,
+	listener: Option<EventHandler>,
+}
+
+impl Registry {
+	fn insert(&mut self, sequence: Sequence, scope: Scope, enabled: Option<State<bool>>, handler: Option<ShortcutFn>) -> Result<u64, ShortcutError> {
+		let conflict = self.bindings.iter().find(|x| x.scope == scope && (x.sequence.is_prefix_of(&sequence) || sequence.is_prefix_of(&x.sequence)));
+		if let Some(existing) = conflict {
+			return Err(ShortcutError::Conflict { shortcut: sequence.to_string(), existing: existing.sequence.to_string() });
+		}
+
+		let id = self.next_id;
+		self.next_id += 1;
+		self.bindings.push(Binding { id, sequence, scope, enabled, handler });
+		Ok(id)
+	}
+
+	fn remove(&mut self, id: u64) -> Option<Binding> {
+		let i = self.bindings.iter().position(|x| x.id == id)?;
+		Some(self.bindings.remove(i))
+	}
+
+	/// Feed a key press, returns the id of the binding that got completed, if any
+	/// `depth` is `None` for bindings out of scope and the scope's nesting depth otherwise, the deepest scope wins
+	fn resolve(&mut self, pressed: Pressed, time: f64, depth: impl Fn(&Binding) -> Option<usize>) -> Option<u64> {
+		if time - self.last_time > SEQUENCE_TIMEOUT { self.pending.clear(); }
+		self.last_time = time;
+		self.pending.push(pressed);
+
+		loop {
+			let pending = &self.pending;
+			let active = self.bindings.iter()
+				.filter(|x| x.sequence.starts_with(pending) && x.is_enabled())
+				.filter_map(|x| depth(x).map(|depth| (x, depth)))
+				.collect::<Vec<_>>();
+			let complete = active.iter().filter(|(x, _)| x.sequence.0.len() == pending.len()).max_by_key(|(_, depth)| *depth);
+			if let Some((binding, _)) = complete {
+				let id = binding.id;
+				self.pending.clear();
+				return Some(id);
+			}
+			if !active.is_empty() { return None; }
+			// the press might start a new sequence rather than continue the current one
+			if self.pending.len() > 1 {
+				self.pending.drain(..self.pending.len() - 1);
+				continue;
+			}
+			self.pending.clear();
+			return None;
+		}
+	}
+}
+
+thread_local! {
+	static REGISTRY: RefCell<Registry> = Default::default();
+}
+
+fn is_editable(element: &web_sys::Element) -> bool {
+	matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT") ||
+		matches!(element.dyn_ref::<web_sys::HtmlElement>(), Some(x) if x.is_content_editable())
+}
+
+fn scope_depth(scope: &Scope, target: Option<&web_sys::Element>) -> Option<usize> {
+	let element = match scope {
+		Scope::Element(x) => x,
+		_ => return Some(0),
+	};
+	let target = target?;
+	if !element.contains(Some(target)) { return None; }
+	let mut depth = 1;
+	let mut current = element.parent_element();
+	while let Some(x) = current {
+		depth += 1;
+		current = x.parent_element();
+	}
+	Some(depth)
+}
+
+fn on_key_down(event: web_sys::KeyboardEvent) {
+	if ["Control", "Alt", "Shift", "Meta"].contains(&event.key().as_str()) { return; }
+
+	let target = event.target().and_then(|x| x.dyn_into::<web_sys::Element>().ok());
+	let editable = matches!(&target, Some(x) if is_editable(x));
+	let pressed = Pressed {
+		ctrl: event.ctrl_key(),
+		alt: event.alt_key(),
+		shift: event.shift_key(),
+		meta: event.meta_key(),
+		key: event.key().to_lowercase(),
+		code: event.code().to_lowercase(),
+	};
+	let handler = REGISTRY.with(|registry| {
+		let mut registry = registry.borrow_mut();
+		// plain keys are left alone while typing
+		let id = registry.resolve(pressed, event.time_stamp(), |x| {
+			if editable && !x.sequence.0.iter().all(Chord::has_modifier) { return None; }
+			scope_depth(&x.scope, target.as_ref())
+		})?;
+		registry.bindings.iter().find(|x| x.id == id).and_then(|x| x.handler.clone())
+	});
+	let handler = if let Some(x) = handler { x } else { return; };
+	event.prevent_default();
+	if let Ok(mut f) = handler.try_borrow_mut() { (*f)(event); };
+}
+
+fn register(shortcut: Shortcut, f: impl FnMut(web_sys::KeyboardEvent) + 'static) -> Result<EventHandler, ShortcutError> {
+	let Shortcut { sequence, scope, enabled } = shortcut;
+	let id = REGISTRY.with(|registry| {
+		let mut registry = registry.borrow_mut();
+		let id = registry.insert(sequence, scope, enabled, Some(Rc::new(RefCell::new(f))))?;
+		if registry.listener.is_none() {
+			registry.listener = Some(crate::dom().on(web_str::keydown(), on_key_down));
+		}
+		Ok(id)
+	})?;
+
+	Ok(EventHandler::from_cleanup(move || {
+		// the binding is dropped after the registry is released, its handler might own other shortcuts
+		let _ = REGISTRY.try_with(|registry| {
+			let mut registry = registry.borrow_mut();
+			let binding = registry.remove(id);
+			let listener = if registry.bindings.is_empty() { registry.listener.take() } else { None };
+			drop(registry);
+			drop((binding, listener));
+		});
+	}))
+}
+
+/// Keyboard shortcuts that are released along with the element they're added to
+pub trait ShortcutTarget: EventTarget {
+	fn add_shortcut(&self, mut shortcut: Shortcut, f: impl FnMut(web_sys::KeyboardEvent) + 'static) -> Result<EventHandler, ShortcutError> {
+		if shortcut.scope == Scope::Owner { shortcut.scope = Scope::Element(self.element().into_owned()); }
+		register(shortcut, f)
+	}
+
+	/// Chaining alternative to `add_shortcut`, panics on conflicts
+	fn shortcut(self, shortcut: Shortcut, f: impl FnMut(web_sys::KeyboardEvent) + 'static) -> Self where Self: Sized {
+		let handler = self.add_shortcut(shortcut, f).expect("can't add shortcut");
+		self.event_handlers().push(handler);
+		self
+	}
+}
+
+impl<T: EventTarget> ShortcutTarget for T {}
@@ -0,0 +1,94 @@
+use super::*;
+
+fn press(keys: &str) -> Pressed {
+	let chord = Chord::parse(keys, false).unwrap();
+	Pressed {
+		ctrl: chord.ctrl,
+		alt: chord.alt,
+		shift: chord.shift,
+		meta: chord.meta,
+		code: code_for_key(&chord.key).unwrap_or_default(),
+		key: chord.key,
+	}
+}
+
+fn global(registry: &mut Registry, keys: &str) -> Result<u64, ShortcutError> {
+	registry.insert(Sequence::parse(keys, false).unwrap(), Scope::Global, None, None)
+}
+
+#[test]
+fn parse_chords() {
+	let chord = Chord::parse("Ctrl+Shift+K", false).unwrap();
+	assert_eq!(chord, Chord { ctrl: true, alt: false, shift: true, meta: false, key: "k".to_owned() });
+	assert_eq!(chord.to_string(), "Ctrl+Shift+k");
+
+	assert!(Chord::parse("Mod+S", true).unwrap().meta);
+	assert!(Chord::parse("Mod+S", false).unwrap().ctrl);
+	assert_eq!(Chord::parse("Ctrl++", false).unwrap().key, "+");
+	assert_eq!(Chord::parse("Esc", false).unwrap().key, "escape");
+	assert_eq!(Chord::parse("Alt+Up", false).unwrap().key, "arrowup");
+
+	assert!(matches!(Chord::parse("Ctrl+", false), Err(ShortcutError::Parse(_))));
+	assert!(matches!(Chord::parse("Hyper+K", false), Err(ShortcutError::Parse(_))));
+	assert!(matches!(Sequence::parse("  ", false), Err(ShortcutError::Parse(_))));
+	assert_eq!(Sequence::parse("g i", false).unwrap().to_string(), "g i");
+}
+
+#[test]
+fn match_chords() {
+	let chord = Chord::parse("Alt+K", false).unwrap();
+	assert!(chord.matches(&Pressed { key: "˚".to_owned(), ..press("Alt+K") }));
+	assert!(!chord.matches(&press("K")));
+	assert!(Chord::parse("?", false).unwrap().matches(&press("Shift+?")));
+	assert!(!Chord::parse("k", false).unwrap().matches(&press("Shift+K")));
+}
+
+#[test]
+fn conflicts() {
+	let mut registry = Registry::default();
+	global(&mut registry, "g i").unwrap();
+	assert!(matches!(global(&mut registry, "g"), Err(ShortcutError::Conflict { .. })));
+	assert!(matches!(global(&mut registry, "G I"), Err(ShortcutError::Conflict { .. })));
+	assert!(matches!(global(&mut registry, "g i x"), Err(ShortcutError::Conflict { .. })));
+	global(&mut registry, "g h").unwrap();
+	global(&mut registry, "Ctrl+G").unwrap();
+}
+
+#[test]
+fn sequences() {
+	let mut registry = Registry::default();
+	let inbox = global(&mut registry, "g i").unwrap();
+	let save = global(&mut registry, "Ctrl+S").unwrap();
+
+	assert_eq!(registry.resolve(press("g"), 0., |_| Some(0)), None);
+	assert_eq!(registry.resolve(press("i"), 100., |_| Some(0)), Some(inbox));
+
+	// too slow
+	assert_eq!(registry.resolve(press("g"), 1000., |_| Some(0)), None);
+	assert_eq!(registry.resolve(press("i"), 3000., |_| Some(0)), None);
+
+	// a stray key restarts the sequence
+	assert_eq!(registry.resolve(press("g"), 4000., |_| Some(0)), None);
+	assert_eq!(registry.resolve(press("g"), 4100., |_| Some(0)), None);
+	assert_eq!(registry.resolve(press("i"), 4200., |_| Some(0)), Some(inbox));
+
+	assert_eq!(registry.resolve(press("g"), 5000., |_| Some(0)), None);
+	assert_eq!(registry.resolve(press("Ctrl+S"), 5100., |_| Some(0)), Some(save));
+}
+
+#[test]
+fn scopes_and_enabled() {
+	let mut registry = Registry::default();
+	let enabled = State::new(false);
+	let outer = global(&mut registry, "Ctrl+K").unwrap();
+	let inner = registry.insert(Sequence::parse("Ctrl+K", false).unwrap(), Scope::Owner, Some(enabled.clone()), None).unwrap();
+
+	let depth = |x: &Binding| Some(if x.scope == Scope::Global { 0 } else { 1 });
+	assert_eq!(registry.resolve(press("Ctrl+K"), 0., depth), Some(outer));
+	*enabled.update() = true;
+	assert_eq!(registry.resolve(press("Ctrl+K"), 0., depth), Some(inner));
+	assert_eq!(registry.resolve(press("Ctrl+K"), 0., |x| if x.scope == Scope::Global { Some(0) } else { None }), Some(outer));
+
+	assert!(registry.remove(inner).is_some());
+	assert_eq!(registry.resolve(press("Ctrl+K"), 0., depth), Some(outer));
+}
@@ -2,7 +2,16 @@
 
 //! pub/sub event-based state management
 
+mod async_state;
+mod batch;
+mod collections;
+mod derived;
+mod history;
+pub mod inspector;
+mod lens;
+mod persist;
 mod state_slice;
+mod store;
 mod tests;
 
 use slotmap::DenseSlotMap;
@@ -13,15 +22,32 @@ use std::{
 };
 use state_slice::StateSliceMeta;
 pub use state_slice::StateSlice;
+pub use store::{Effect, Logger, Middleware, Store};
+pub use async_state::{Changes, Task, WaitFor};
+pub use batch::{batch, batch_deferred, Flush};
+pub use collections::{MapDiff, StateMap, StateVec, VecDiff};
+pub use derived::{Derived, Sources};
+pub use history::{History, Patch};
+pub use lens::{Focus, Lens};
+pub use persist::{MemoryStorage, Persist, Storage, WebStorage};
 pub use crate::{__view as view, __update as update};
 
 slotmap::new_key_type! {pub struct SubscriptionKey;}
 
-pub struct Subscription(Weak<RefCell<StateSliceMeta>>, SubscriptionKey);
+/// Anything that keeps a list of subscribers a `Subscription` can be removed from
+trait Unsubscribe {
+	fn unsubscribe(&self, key: SubscriptionKey);
+}
+
+impl Unsubscribe for RefCell<StateSliceMeta> {
+	fn unsubscribe(&self, key: SubscriptionKey) { self.borrow_mut().subscribers.remove(key); }
+}
+
+pub struct Subscription(Weak<dyn Unsubscribe>, SubscriptionKey);
 impl Drop for Subscription {
 	fn drop(&mut self) {
-		let meta = if let Some(x) = self.0.upgrade() { x } else { return; };
-		meta.borrow_mut().subscribers.remove(self.1);
+		let target = if let Some(x) = self.0.upgrade() { x } else { return; };
+		target.unsubscribe(self.1);
 	}
 }
 
@@ -58,6 +84,32 @@ impl<'a, T> Drop for StateGuard<'a, T> {
 	}
 }
 
+/// Only notifies subscribers if the value is different from what it was when the guard was created
+struct ChangeGuard<'a, T: PartialEq> {
+	state: Option<RefMut<'a, StateSlice<T>>>,
+	old: T,
+}
+
+impl<'a, T: PartialEq> Deref for ChangeGuard<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target { &self.state.as_ref().unwrap().data }
+}
+
+impl<'a, T: PartialEq> DerefMut for ChangeGuard<'a, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.state.as_mut().unwrap().data }
+}
+
+impl<'a, T: PartialEq> Drop for ChangeGuard<'a, T> {
+	fn drop(&mut self) {
+		let state = self.state.take().unwrap();
+		if state.data == self.old { return; }
+		let meta = state.meta.clone();
+		drop(state);
+		StateSliceMeta::trigger_update(&meta);
+	}
+}
+
 impl<T: 'static> State<T> {
 	pub fn new(initial: T) -> Self { State(Rc::new(RefCell::new(StateSlice::new(initial)))) }
 
@@ -65,6 +117,9 @@ impl<T: 'static> State<T> {
 		StateGuard { state: Some(self.0.borrow_mut()) }
 	}
 
+	/// Same as `update`, but subscribers aren't notified
+	pub fn update_silent<'a>(&'a self) -> impl DerefMut<Target = T> + 'a { RefMut::map(self.0.borrow_mut(), |x| &mut x.data) }
+
 	pub fn view<'a>(&'a self) -> impl Deref<Target = T> + 'a { Ref::map(self.0.borrow(), StateSlice::view) }
 
 	pub fn subscribe_key(&self, f: impl FnMut() + 'static) -> SubscriptionKey {
@@ -74,10 +129,43 @@ impl<T: 'static> State<T> {
 	#[must_use]
 	pub fn subscribe(&self, f: impl FnMut() + 'static) -> Subscription {
 		let state_slice = self.0.borrow();
-		Subscription(Rc::downgrade(&state_slice.meta), state_slice.subscribe_key(f))
+		Subscription(Rc::downgrade(&state_slice.meta) as Weak<dyn Unsubscribe>, state_slice.subscribe_key(f))
 	}
 
 	fn unsubscribe(&self, key: SubscriptionKey) { self.0.borrow().unsubscribe(key) }
+
+	/// Notify subscribers after changing the value through `update_silent`
+	fn notify(&self) {
+		let meta = Rc::clone(&self.0.borrow().meta);
+		StateSliceMeta::trigger_update(&meta);
+	}
+}
+
+impl<T: PartialEq + Clone + 'static> State<T> {
+	/// Same as `update`, but subscribers are only notified if the value is different afterwards
+	/// writing the same value from inside a subscriber doesn't count towards the nested update limit
+	pub fn update_if_changed<'a>(&'a self) -> impl DerefMut<Target = T> + 'a {
+		let state = self.0.borrow_mut();
+		let old = state.data.clone();
+		ChangeGuard { state: Some(state), old }
+	}
+
+	/// Subscribe with the previous and the current value, `f` is only called when they're different
+	#[must_use]
+	pub fn subscribe_changes(&self, mut f: impl FnMut(&T, &T) + 'static) -> Subscription {
+		let mut last = self.view().clone();
+		let state = Rc::downgrade(&self.0);
+		self.subscribe(move || {
+			let state = if let Some(x) = state.upgrade() { x } else { return; };
+			let current = {
+				let state = state.borrow();
+				if state.data == last { return; }
+				state.data.clone()
+			};
+			let old = std::mem::replace(&mut last, current.clone());
+			f(&old, &current);
+		})
+	}
 }
 
 #[macro_export]
@@ -0,0 +1,150 @@
+use super::*;
+use futures::{
+	future::{AbortHandle, Abortable},
+	Stream, StreamExt,
+};
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll, Waker},
+};
+
+/// A spawned task that's cancelled when dropped
+#[must_use]
+pub struct Task(Option<AbortHandle>);
+
+impl Task {
+	fn spawn(f: impl Future<Output = ()> + 'static) -> Self {
+		let (handle, registration) = AbortHandle::new_pair();
+		wasm_bindgen_futures::spawn_local(async move { let _ = Abortable::new(f, registration).await; });
+		Self(Some(handle))
+	}
+
+	/// Let the task run to completion even though nothing is holding on to it
+	pub fn detach(mut self) { self.0 = None; }
+}
+
+impl Drop for Task {
+	fn drop(&mut self) {
+		if let Some(x) = self.0.take() { x.abort(); }
+	}
+}
+
+struct Latest<T> {
+	value: Option<T>,
+	waker: Option<Waker>,
+}
+
+/// Wakes the stream when the subscriber it's captured by is dropped along with the state, so that the stream can end
+struct WakeOnDrop<T>(Weak<RefCell<Latest<T>>>);
+
+impl<T> Drop for WakeOnDrop<T> {
+	fn drop(&mut self) {
+		let waker = if let Some(x) = self.0.upgrade() { x.borrow_mut().waker.take() } else { return; };
+		if let Some(x) = waker { x.wake(); }
+	}
+}
+
+/// Snapshots of a state, see `State::changes`
+pub struct Changes<T> {
+	latest: Rc<RefCell<Latest<T>>>,
+	state: Weak<RefCell<StateSlice<T>>>,
+	_subscription: Subscription,
+}
+
+impl<T> Stream for Changes<T> {
+	type Item = T;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+		let mut latest = self.latest.borrow_mut();
+		if let Some(x) = latest.value.take() { return Poll::Ready(Some(x)); }
+		if self.state.strong_count() == 0 { return Poll::Ready(None); }
+		latest.waker = Some(cx.waker().clone());
+		Poll::Pending
+	}
+}
+
+/// Resolves with a snapshot of a state once it matches a predicate, see `State::wait_for`
+pub struct WaitFor<T> {
+	state: State<T>,
+	predicate: Box<dyn FnMut(&T) -> bool>,
+	waker: Rc<RefCell<Option<Waker>>>,
+	_subscription: Subscription,
+}
+
+impl<T: Clone + 'static> Future for WaitFor<T> {
+	type Output = T;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+		let this = self.get_mut();
+		let value = this.state.view();
+		if (this.predicate)(&value) { return Poll::Ready((*value).clone()); }
+		*this.waker.borrow_mut() = Some(cx.waker().clone());
+		Poll::Pending
+	}
+}
+
+impl<T: Clone + 'static> State<T> {
+	/// A stream of snapshots taken after every update, starting with the next one
+	/// if it isn't polled in time for an update, only the latest snapshot is kept
+	/// the stream ends once the state is dropped
+	pub fn changes(&self) -> Changes<T> {
+		let latest = Rc::new(RefCell::new(Latest { value: None, waker: None }));
+		let (weak_latest, weak_state) = (Rc::downgrade(&latest), Rc::downgrade(&self.0));
+		let wake_on_drop = WakeOnDrop(Rc::downgrade(&latest));
+		let subscription = self.subscribe(move || {
+			let _ = &wake_on_drop;
+			let (latest, state) = if let (Some(x), Some(y)) = (weak_latest.upgrade(), weak_state.upgrade()) { (x, y) } else { return; };
+			let waker = {
+				let mut latest = latest.borrow_mut();
+				latest.value = Some(state.borrow().data.clone());
+				latest.waker.take()
+			};
+			if let Some(x) = waker { x.wake(); }
+		});
+		Changes { latest, state: Rc::downgrade(&self.0), _subscription: subscription }
+	}
+
+	/// Resolves as soon as `predicate` is true for the value, which can be right away
+	/// ```rust,ignore
+	/// let user = session.wait_for(|x| x.user.is_some()).await.user.unwrap();
+	/// ```
+	pub fn wait_for(&self, predicate: impl FnMut(&T) -> bool + 'static) -> WaitFor<T> {
+		let waker = Rc::new(RefCell::new(None::<Waker>));
+		let weak = Rc::downgrade(&waker);
+		let subscription = self.subscribe(move || {
+			let waker = if let Some(x) = weak.upgrade() { x } else { return; };
+			let waker = waker.borrow_mut().take();
+			if let Some(x) = waker { x.wake(); }
+		});
+		WaitFor { state: self.clone(), predicate: Box::new(predicate), waker, _subscription: subscription }
+	}
+}
+
+impl<T: 'static> State<T> {
+	/// A state that's set to what `future` resolves with, and is `initial` until then
+	/// dropping the `Task` cancels the future, the task doesn't keep the state alive either way
+	pub fn from_future(initial: T, future: impl Future<Output = T> + 'static) -> (Self, Task) {
+		let state = Self::new(initial);
+		let weak = Rc::downgrade(&state.0);
+		let task = Task::spawn(async move {
+			let value = future.await;
+			if let Some(x) = weak.upgrade() { *State(x).update() = value; }
+		});
+		(state, task)
+	}
+
+	/// A state that's set to every item of `stream`, and is `initial` until the first one
+	/// dropping the `Task` cancels the stream, the task doesn't keep the state alive either way
+	pub fn from_stream(initial: T, stream: impl Stream<Item = T> + 'static) -> (Self, Task) {
+		let state = Self::new(initial);
+		let weak = Rc::downgrade(&state.0);
+		let task = Task::spawn(async move {
+			let mut stream = Box::pin(stream);
+			while let Some(value) = stream.next().await {
+				if let Some(x) = weak.upgrade() { *State(x).update() = value; } else { return; }
+			}
+		});
+		(state, task)
+	}
+}
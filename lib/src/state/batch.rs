@@ -0,0 +1,131 @@
+use super::*;
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// When notifications of a `batch_deferred` are flushed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flush {
+	Microtask,
+	AnimationFrame,
+}
+
+type Meta = Rc<RefCell<StateSliceMeta>>;
+
+#[derive(Default)]
+struct Batch {
+	depth: usize,
+	/// how many of the batches that are running are `batch_deferred`
+	deferred_depth: usize,
+	/// a deferred flush has been scheduled but hasn't run yet
+	scheduled: bool,
+	/// notified when the outermost batch ends
+	pending: Vec<Meta>,
+	/// notified by the scheduled flush
+	deferred: Vec<Meta>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+	#[wasm_bindgen(js_name = queueMicrotask)]
+	fn queue_microtask(f: &JsValue);
+}
+
+thread_local! {
+	static BATCH: RefCell<Batch> = Default::default();
+}
+
+fn push_unique(list: &mut Vec<Meta>, meta: &Meta) {
+	if !list.iter().any(|x| Rc::ptr_eq(x, meta)) { list.push(Rc::clone(meta)); }
+}
+
+/// Called on every update, returns whether notifying subscribers should be left to the end of the batch
+pub(super) fn defer(meta: &Meta) -> bool {
+	BATCH.with(|batch| {
+		let mut batch = batch.borrow_mut();
+		if batch.deferred_depth > 0 {
+			push_unique(&mut batch.deferred, meta);
+		} else if batch.depth > 0 {
+			push_unique(&mut batch.pending, meta);
+		} else {
+			return false;
+		}
+		true
+	})
+}
+
+/// States that didn't get notified because a subscriber panicked, they're put back to go out with the next batch
+struct Unflushed(std::vec::IntoIter<Meta>);
+
+impl Drop for Unflushed {
+	fn drop(&mut self) {
+		BATCH.with(|batch| {
+			let mut batch = batch.borrow_mut();
+			for meta in &mut self.0 { push_unique(&mut batch.pending, &meta); }
+		});
+	}
+}
+
+fn flush(take: impl FnOnce(&mut Batch) -> Vec<Meta>) {
+	let mut pending = Unflushed(BATCH.with(|batch| take(&mut batch.borrow_mut())).into_iter());
+	for meta in &mut pending.0 {
+		StateSliceMeta::trigger_update(&meta);
+	}
+}
+
+struct BatchGuard {
+	deferred: bool,
+}
+
+impl BatchGuard {
+	fn new(deferred: bool) -> Self {
+		BATCH.with(|batch| {
+			let mut batch = batch.borrow_mut();
+			batch.depth += 1;
+			if deferred { batch.deferred_depth += 1; }
+		});
+		Self { deferred }
+	}
+}
+
+impl Drop for BatchGuard {
+	fn drop(&mut self) {
+		let outermost = BATCH.with(|batch| {
+			let mut batch = batch.borrow_mut();
+			batch.depth -= 1;
+			if self.deferred { batch.deferred_depth -= 1; }
+			batch.depth == 0
+		});
+		// running subscribers while unwinding would abort if one of them panicked too, so they wait for the next batch
+		if outermost && !std::thread::panicking() { flush(|batch| std::mem::take(&mut batch.pending)); }
+	}
+}
+
+/// Run `f`, holding off notifying subscribers until the outermost batch ends
+/// each state's subscribers are notified once, no matter how many times it was updated
+/// if `f` or a subscriber panics, the states that weren't notified yet are notified at the end of the next batch
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+	let _guard = BatchGuard::new(false);
+	f()
+}
+
+/// Same as `batch`, but notifications are held off until the next microtask or animation frame,
+/// merging the updates of all `batch_deferred` calls until then
+/// only updates made inside `f` are held off, ones made elsewhere in the meantime notify subscribers right away as usual
+pub fn batch_deferred<R>(flush_at: Flush, f: impl FnOnce() -> R) -> R {
+	let _guard = BatchGuard::new(true);
+	let schedule = BATCH.with(|batch| !std::mem::replace(&mut batch.borrow_mut().scheduled, true));
+	if schedule {
+		let callback = Closure::once_into_js(move || {
+			flush(|batch| {
+				batch.scheduled = false;
+				std::mem::take(&mut batch.deferred)
+			});
+		});
+		match flush_at {
+			Flush::Microtask => queue_microtask(&callback),
+			Flush::AnimationFrame => {
+				web_sys::window().expect("no window").request_animation_frame(callback.unchecked_ref::<js_sys::Function>()).expect("can't request animation frame");
+			},
+		}
+	}
+	f()
+}
@@ -0,0 +1,225 @@
+use super::*;
+use std::{
+	cell::Cell,
+	collections::{HashMap, HashSet, VecDeque},
+	hash::Hash,
+};
+
+type DiffFn<D> = Rc<RefCell<dyn FnMut(&D)>>;
+
+/// Hands diffs out to subscribers in the order they happened,
+/// diffs emitted from inside a subscriber are queued until every subscriber has seen the current one
+struct Emitter<D> {
+	subscribers: RefCell<DenseSlotMap<SubscriptionKey, DiffFn<D>>>,
+	queue: RefCell<VecDeque<D>>,
+	emitting: Cell<bool>,
+}
+
+impl<D> Default for Emitter<D> {
+	fn default() -> Self { Self { subscribers: Default::default(), queue: Default::default(), emitting: Cell::new(false) } }
+}
+
+impl<D> Emitter<D> {
+	fn emit(&self, diff: D) {
+		self.queue.borrow_mut().push_back(diff);
+		if self.emitting.replace(true) { return; }
+		loop {
+			let diff = if let Some(x) = self.queue.borrow_mut().pop_front() { x } else { break; };
+			let snapshot = self.subscribers.borrow().values().cloned().collect::<Vec<_>>();
+			for subscriber in snapshot {
+				let subscriber = &mut *subscriber.borrow_mut();
+				subscriber(&diff);
+			}
+		}
+		self.emitting.set(false);
+	}
+}
+
+impl<D> Unsubscribe for Emitter<D> {
+	fn unsubscribe(&self, key: SubscriptionKey) { self.subscribers.borrow_mut().remove(key); }
+}
+
+fn subscribe_diffs<D: 'static>(emitter: &Rc<Emitter<D>>, f: impl FnMut(&D) + 'static) -> Subscription {
+	let key = emitter.subscribers.borrow_mut().insert(Rc::new(RefCell::new(f)));
+	Subscription(Rc::downgrade(emitter) as Weak<dyn Unsubscribe>, key)
+}
+
+/// A single change to a `StateVec`, indices are as of right before the change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VecDiff<T> {
+	Insert { index: usize, value: T },
+	Remove { index: usize },
+	Move { from: usize, to: usize },
+	Update { index: usize, value: T },
+	Clear,
+}
+
+/// A `Vec` that tells diff subscribers exactly what changed instead of just that something did
+/// plain `subscribe` works the same as with `State<Vec<T>>`
+pub struct StateVec<T> {
+	state: State<Vec<T>>,
+	emitter: Rc<Emitter<VecDiff<T>>>,
+}
+
+impl<T> Clone for StateVec<T> {
+	fn clone(&self) -> Self { Self { state: self.state.clone(), emitter: Rc::clone(&self.emitter) } }
+}
+
+impl<T: Clone + 'static> Default for StateVec<T> {
+	fn default() -> Self { Self::new(Vec::new()) }
+}
+
+impl<T: Clone + 'static> StateVec<T> {
+	pub fn new(items: Vec<T>) -> Self { Self { state: State::new(items), emitter: Default::default() } }
+
+	pub fn view<'a>(&'a self) -> impl Deref<Target = Vec<T>> + 'a { self.state.view() }
+	pub fn len(&self) -> usize { self.view().len() }
+	pub fn is_empty(&self) -> bool { self.view().is_empty() }
+
+	#[must_use]
+	pub fn subscribe(&self, f: impl FnMut() + 'static) -> Subscription { self.state.subscribe(f) }
+
+	/// Subscribe to every change as it happens, starting with the next one
+	#[must_use]
+	pub fn subscribe_diffs(&self, f: impl FnMut(&VecDiff<T>) + 'static) -> Subscription { subscribe_diffs(&self.emitter, f) }
+
+	fn apply(&self, diff: VecDiff<T>) -> Option<T> {
+		let removed = {
+			let mut items = self.state.update_silent();
+			match &diff {
+				VecDiff::Insert { index, value } => { items.insert(*index, value.clone()); None },
+				VecDiff::Remove { index } => Some(items.remove(*index)),
+				VecDiff::Move { from, to } => { let item = items.remove(*from); items.insert(*to, item); None },
+				VecDiff::Update { index, value } => Some(std::mem::replace(&mut items[*index], value.clone())),
+				VecDiff::Clear => { items.clear(); None },
+			}
+		};
+		self.emitter.emit(diff);
+		self.state.notify();
+		removed
+	}
+
+	pub fn push(&self, value: T) { self.insert(self.len(), value); }
+	pub fn insert(&self, index: usize, value: T) { self.apply(VecDiff::Insert { index, value }); }
+	pub fn remove(&self, index: usize) -> T { self.apply(VecDiff::Remove { index }).unwrap() }
+	pub fn pop(&self) -> Option<T> { if self.is_empty() { None } else { Some(self.remove(self.len() - 1)) } }
+	pub fn move_item(&self, from: usize, to: usize) { self.apply(VecDiff::Move { from, to }); }
+	pub fn clear(&self) { self.apply(VecDiff::Clear); }
+
+	/// Replace an element, returning the previous one
+	pub fn set(&self, index: usize, value: T) -> T { self.apply(VecDiff::Update { index, value }).unwrap() }
+
+	pub fn update_at(&self, index: usize, f: impl FnOnce(&mut T)) {
+		let mut value = self.view()[index].clone();
+		f(&mut value);
+		self.set(index, value);
+	}
+
+	/// Replace all elements, emitting a `Clear` followed by an `Insert` for each element
+	pub fn replace(&self, items: Vec<T>) {
+		batch(|| {
+			self.clear();
+			for (index, value) in items.into_iter().enumerate() { self.insert(index, value); }
+		});
+	}
+
+	/// Turn the current elements into `items` with as few diffs as it can figure out, matching elements up by `key`
+	/// elements with a key that's gone are removed, the rest are moved into place and updated if they're different
+	pub fn reconcile<K: Eq + Hash>(&self, items: Vec<T>, key: impl Fn(&T) -> K) where T: PartialEq {
+		batch(|| {
+			let keys = items.iter().map(&key).collect::<HashSet<_>>();
+			for index in (0..self.len()).rev() {
+				if !keys.contains(&key(&self.view()[index])) { self.remove(index); }
+			}
+
+			let len = items.len();
+			for (index, value) in items.into_iter().enumerate() {
+				let wanted = key(&value);
+				let found = self.view().iter().skip(index).position(|x| key(x) == wanted).map(|x| x + index);
+				match found {
+					Some(from) => {
+						if from != index { self.move_item(from, index); }
+						if self.view()[index] != value { self.set(index, value); }
+					},
+					None => self.insert(index, value),
+				}
+			}
+
+			// leftovers with duplicate keys
+			while self.len() > len { self.pop(); }
+		});
+	}
+}
+
+/// A single change to a `StateMap`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapDiff<K, V> {
+	Insert { key: K, value: V },
+	Remove { key: K },
+	Update { key: K, value: V },
+	Clear,
+}
+
+/// A `HashMap` that tells diff subscribers exactly what changed, same as `StateVec`
+pub struct StateMap<K, V> {
+	state: State<HashMap<K, V>>,
+	emitter: Rc<Emitter<MapDiff<K, V>>>,
+}
+
+impl<K, V> Clone for StateMap<K, V> {
+	fn clone(&self) -> Self { Self { state: self.state.clone(), emitter: Rc::clone(&self.emitter) } }
+}
+
+impl<K: Eq + Hash + Clone + 'static, V: Clone + 'static> Default for StateMap<K, V> {
+	fn default() -> Self { Self::new(HashMap::new()) }
+}
+
+impl<K: Eq + Hash + Clone + 'static, V: Clone + 'static> StateMap<K, V> {
+	pub fn new(items: HashMap<K, V>) -> Self { Self { state: State::new(items), emitter: Default::default() } }
+
+	pub fn view<'a>(&'a self) -> impl Deref<Target = HashMap<K, V>> + 'a { self.state.view() }
+	pub fn len(&self) -> usize { self.view().len() }
+	pub fn is_empty(&self) -> bool { self.view().is_empty() }
+	pub fn contains_key(&self, key: &K) -> bool { self.view().contains_key(key) }
+
+	#[must_use]
+	pub fn subscribe(&self, f: impl FnMut() + 'static) -> Subscription { self.state.subscribe(f) }
+
+	/// Subscribe to every change as it happens, starting with the next one
+	#[must_use]
+	pub fn subscribe_diffs(&self, f: impl FnMut(&MapDiff<K, V>) + 'static) -> Subscription { subscribe_diffs(&self.emitter, f) }
+
+	fn apply(&self, diff: MapDiff<K, V>) -> Option<V> {
+		let removed = {
+			let mut items = self.state.update_silent();
+			match &diff {
+				MapDiff::Insert { key, value } | MapDiff::Update { key, value } => items.insert(key.clone(), value.clone()),
+				MapDiff::Remove { key } => items.remove(key),
+				MapDiff::Clear => { items.clear(); None },
+			}
+		};
+		self.emitter.emit(diff);
+		self.state.notify();
+		removed
+	}
+
+	/// Emits an `Update` if the key was already present, returning the previous value
+	pub fn insert(&self, key: K, value: V) -> Option<V> {
+		if self.contains_key(&key) { self.apply(MapDiff::Update { key, value }) } else { self.apply(MapDiff::Insert { key, value }) }
+	}
+
+	pub fn remove(&self, key: &K) -> Option<V> {
+		if !self.contains_key(key) { return None; }
+		self.apply(MapDiff::Remove { key: key.clone() })
+	}
+
+	pub fn clear(&self) { self.apply(MapDiff::Clear); }
+
+	/// Returns whether the key was present
+	pub fn update_at(&self, key: &K, f: impl FnOnce(&mut V)) -> bool {
+		let mut value = if let Some(x) = self.view().get(key) { x.clone() } else { return false; };
+		f(&mut value);
+		self.apply(MapDiff::Update { key: key.clone(), value });
+		true
+	}
+}
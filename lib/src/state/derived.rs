@@ -0,0 +1,116 @@
+use super::*;
+use std::cell::Cell;
+
+struct DerivedInner<U> {
+	state: State<U>,
+	dirty: Cell<bool>,
+	compute: RefCell<Box<dyn FnMut() -> U>>,
+	subscriptions: RefCell<Vec<Subscription>>,
+}
+
+impl<U: PartialEq + 'static> DerivedInner<U> {
+	fn refresh(&self) {
+		if !self.dirty.replace(false) { return; }
+		let value = (*self.compute.borrow_mut())();
+		if *self.state.view() == value { return; }
+		self.state.0.borrow_mut().data = value;
+		let meta = Rc::clone(&self.state.0.borrow().meta);
+		StateSliceMeta::trigger_update(&meta);
+	}
+
+	fn has_subscribers(&self) -> bool { !self.state.0.borrow().meta.borrow().subscribers.is_empty() }
+}
+
+/// A value computed from other states, see `State::map` and `State::combine`
+/// it's only recomputed when a source changes and it is either viewed or has subscribers,
+/// and its own subscribers are only notified when the computed value is different
+/// dropping the last clone unsubscribes from the sources
+pub struct Derived<U>(Rc<DerivedInner<U>>);
+
+impl<U> Clone for Derived<U> {
+	fn clone(&self) -> Self { Self(Rc::clone(&self.0)) }
+}
+
+impl<U: PartialEq + 'static> Derived<U> {
+	fn new<S: Sources<F, U>, F: 'static>(sources: S, mut f: F) -> Self {
+		let sources = Rc::new(sources);
+		let compute_sources = Rc::clone(&sources);
+		Self::from_parts(move || compute_sources.compute(&mut f), move |notify| sources.subscribe(notify))
+	}
+
+	pub fn view<'a>(&'a self) -> impl Deref<Target = U> + 'a {
+		self.0.refresh();
+		self.0.state.view()
+	}
+
+	#[must_use]
+	pub fn subscribe(&self, f: impl FnMut() + 'static) -> Subscription {
+		self.0.refresh();
+		self.0.state.subscribe(f)
+	}
+
+	/// Derive further from this value
+	pub fn map<V: PartialEq + 'static>(&self, mut f: impl FnMut(&U) -> V + 'static) -> Derived<V> {
+		let this = self.clone();
+		let subscribe_to = self.clone();
+		Derived::from_parts(move || f(&this.view()), move |notify| vec![subscribe_to.subscribe(move || notify())])
+	}
+
+	fn from_parts(mut compute: impl FnMut() -> U + 'static, subscribe: impl FnOnce(Rc<dyn Fn()>) -> Vec<Subscription>) -> Self {
+		let inner = Rc::new(DerivedInner {
+			state: State::new(compute()),
+			dirty: Cell::new(false),
+			compute: RefCell::new(Box::new(compute)),
+			subscriptions: RefCell::new(Vec::new()),
+		});
+
+		let weak = Rc::downgrade(&inner);
+		*inner.subscriptions.borrow_mut() = subscribe(Rc::new(move || {
+			let inner = if let Some(x) = weak.upgrade() { x } else { return; };
+			inner.dirty.set(true);
+			if inner.has_subscribers() { inner.refresh(); }
+		}));
+
+		Self(inner)
+	}
+}
+
+/// Tuples of states that can be combined into a `Derived` by a function taking references to all of their values
+pub trait Sources<F, U>: 'static {
+	fn compute(&self, f: &mut F) -> U;
+	fn subscribe(&self, f: Rc<dyn Fn()>) -> Vec<Subscription>;
+}
+
+macro_rules! impl_sources {
+	($($t:ident $i:tt),+) => {
+		impl<$($t: 'static,)+ F: FnMut($(&$t),+) -> U, U> Sources<F, U> for ($(State<$t>,)+) {
+			fn compute(&self, f: &mut F) -> U { f($(&self.$i.view()),+) }
+
+			fn subscribe(&self, f: Rc<dyn Fn()>) -> Vec<Subscription> {
+				vec![$({ let f = Rc::clone(&f); self.$i.subscribe(move || f()) }),+]
+			}
+		}
+	};
+}
+
+impl_sources!(A 0);
+impl_sources!(A 0, B 1);
+impl_sources!(A 0, B 1, C 2);
+impl_sources!(A 0, B 1, C 2, D 3);
+impl_sources!(A 0, B 1, C 2, D 3, E 4);
+impl_sources!(A 0, B 1, C 2, D 3, E 4, G 5);
+
+impl<T: 'static> State<T> {
+	/// A `Derived` value computed from this state
+	pub fn map<U: PartialEq + 'static>(&self, mut f: impl FnMut(&T) -> U + 'static) -> Derived<U> {
+		Derived::new((self.clone(),), move |x: &T| f(x))
+	}
+}
+
+impl<U: PartialEq + 'static> State<U> {
+	/// A `Derived` value computed from a tuple of states
+	/// ```rust,ignore
+	/// let total = State::combine((price.clone(), quantity.clone()), |price: &f64, quantity: &u32| price * *quantity as f64);
+	/// ```
+	pub fn combine<S: Sources<F, U>, F: 'static>(sources: S, f: F) -> Derived<U> { Derived::new(sources, f) }
+}
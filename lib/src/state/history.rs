@@ -0,0 +1,198 @@
+use super::*;
+use std::{cell::Cell, collections::VecDeque};
+
+/// A reversible change, for when keeping snapshots of the whole value around would be too expensive
+pub trait Patch<T> {
+	fn apply(&self, value: &mut T);
+	fn revert(&self, value: &mut T);
+}
+
+struct Snapshot<T> {
+	before: T,
+	after: T,
+}
+
+impl<T: Clone> Patch<T> for Snapshot<T> {
+	fn apply(&self, value: &mut T) { *value = self.after.clone(); }
+	fn revert(&self, value: &mut T) { *value = self.before.clone(); }
+}
+
+/// The value as of the last recorded change, which the next one is diffed against
+struct Tracker<T> {
+	last: RefCell<T>,
+}
+
+type Entry<T> = Vec<Box<dyn Patch<T>>>;
+type SyncFn<T> = Box<dyn Fn(&T)>;
+
+struct HistoryInner<T> {
+	state: State<T>,
+	undo: RefCell<VecDeque<Entry<T>>>,
+	redo: RefCell<Vec<Entry<T>>>,
+	group: RefCell<Entry<T>>,
+	group_depth: Cell<usize>,
+	limit: Cell<usize>,
+	can_undo: State<bool>,
+	can_redo: State<bool>,
+	/// re-synced with the value after undoing or redoing, so that it isn't recorded as a new change
+	sync: RefCell<Option<SyncFn<T>>>,
+	tracking: RefCell<Option<Subscription>>,
+}
+
+/// Undo and redo for a `State`
+/// `History::new` records a snapshot of the value every time the state is updated, no matter how,
+/// while `History::with_patches` only records the `Patch`es applied through `History::apply`
+/// ```rust,ignore
+/// let history = History::new(document.clone());
+/// history.group(|| { document.update().title = title; document.update().modified = now; });
+/// button.on_click(move |_| { history.undo(); });
+/// ```
+pub struct History<T>(Rc<HistoryInner<T>>);
+
+impl<T> Clone for History<T> {
+	fn clone(&self) -> Self { Self(Rc::clone(&self.0)) }
+}
+
+impl<T: Clone + PartialEq + 'static> History<T> {
+	pub fn new(state: State<T>) -> Self {
+		let this = Self::with_patches(state);
+		let tracker = Rc::new(Tracker { last: RefCell::new(this.0.state.view().clone()) });
+		*this.0.sync.borrow_mut() = Some(Box::new({
+			let tracker = Rc::clone(&tracker);
+			move |value| *tracker.last.borrow_mut() = value.clone()
+		}));
+		let weak = Rc::downgrade(&this.0);
+		*this.0.tracking.borrow_mut() = Some(this.0.state.subscribe(move || {
+			let inner = if let Some(x) = weak.upgrade() { x } else { return; };
+			let after = {
+				let current = inner.state.view();
+				if *current == *tracker.last.borrow() { return; }
+				current.clone()
+			};
+			let before = tracker.last.replace(after.clone());
+			History(inner).record(Box::new(Snapshot { before, after }));
+		}));
+		this
+	}
+}
+
+impl<T: 'static> History<T> {
+	pub fn with_patches(state: State<T>) -> Self {
+		Self(Rc::new(HistoryInner {
+			state,
+			undo: Default::default(),
+			redo: Default::default(),
+			group: Default::default(),
+			group_depth: Cell::new(0),
+			limit: Cell::new(100),
+			can_undo: State::new(false),
+			can_redo: State::new(false),
+			sync: RefCell::new(None),
+			tracking: RefCell::new(None),
+		}))
+	}
+
+	/// Keep at most `limit` entries to undo, dropping the oldest ones, 100 by default
+	pub fn with_limit(self, limit: usize) -> Self { self.set_limit(limit); self }
+
+	pub fn set_limit(&self, limit: usize) {
+		self.0.limit.set(limit);
+		let mut undo = self.0.undo.borrow_mut();
+		while undo.len() > limit { undo.pop_front(); }
+		drop(undo);
+		self.refresh_flags();
+	}
+
+	pub fn state(&self) -> &State<T> { &self.0.state }
+	pub fn can_undo(&self) -> &State<bool> { &self.0.can_undo }
+	pub fn can_redo(&self) -> &State<bool> { &self.0.can_redo }
+
+	/// Apply `patch` to the state and record it
+	pub fn apply(&self, patch: impl Patch<T> + 'static) {
+		self.replay(|value| patch.apply(value));
+		self.record(Box::new(patch));
+	}
+
+	/// Record all changes made in `f` as a single entry
+	pub fn group<R>(&self, f: impl FnOnce() -> R) -> R {
+		self.start_group();
+		let res = f();
+		self.end_group();
+		res
+	}
+
+	/// Same as `group`, for changes that don't happen all at once, like the moves of a drag
+	/// groups nest, and changes are recorded as one entry once the outermost group ends
+	pub fn start_group(&self) { self.0.group_depth.set(self.0.group_depth.get() + 1); }
+
+	pub fn end_group(&self) {
+		let depth = self.0.group_depth.get();
+		if depth == 0 { return; }
+		self.0.group_depth.set(depth - 1);
+		if depth > 1 { return; }
+		let group = std::mem::take(&mut *self.0.group.borrow_mut());
+		if !group.is_empty() { self.push(group); }
+	}
+
+	/// Returns `false` if there was nothing to undo
+	pub fn undo(&self) -> bool {
+		self.close_groups();
+		let entry = if let Some(x) = self.0.undo.borrow_mut().pop_back() { x } else { return false; };
+		self.replay(|value| entry.iter().rev().for_each(|patch| patch.revert(value)));
+		self.0.redo.borrow_mut().push(entry);
+		self.refresh_flags();
+		true
+	}
+
+	/// Returns `false` if there was nothing to redo
+	pub fn redo(&self) -> bool {
+		self.close_groups();
+		let entry = if let Some(x) = self.0.redo.borrow_mut().pop() { x } else { return false; };
+		self.replay(|value| entry.iter().for_each(|patch| patch.apply(value)));
+		self.0.undo.borrow_mut().push_back(entry);
+		self.refresh_flags();
+		true
+	}
+
+	/// Forget everything recorded so far
+	pub fn clear(&self) {
+		self.0.undo.borrow_mut().clear();
+		self.0.redo.borrow_mut().clear();
+		self.0.group.borrow_mut().clear();
+		self.refresh_flags();
+	}
+
+	fn close_groups(&self) {
+		while self.0.group_depth.get() > 0 { self.end_group(); }
+	}
+
+	/// Change the value without it being recorded
+	fn replay(&self, f: impl FnOnce(&mut T)) {
+		f(&mut self.0.state.update_silent());
+		if let Some(sync) = &*self.0.sync.borrow() { sync(&self.0.state.view()); }
+		self.0.state.notify();
+	}
+
+	fn record(&self, patch: Box<dyn Patch<T>>) {
+		if self.0.group_depth.get() > 0 {
+			self.0.group.borrow_mut().push(patch);
+		} else {
+			self.push(vec![patch]);
+		}
+	}
+
+	fn push(&self, entry: Entry<T>) {
+		{
+			let mut undo = self.0.undo.borrow_mut();
+			undo.push_back(entry);
+			while undo.len() > self.0.limit.get() { undo.pop_front(); }
+		}
+		self.0.redo.borrow_mut().clear();
+		self.refresh_flags();
+	}
+
+	fn refresh_flags(&self) {
+		*self.0.can_undo.update_if_changed() = !self.0.undo.borrow().is_empty();
+		*self.0.can_redo.update_if_changed() = !self.0.redo.borrow().is_empty();
+	}
+}
@@ -0,0 +1,253 @@
+//! opt-in debugging of states
+//!
+//! once `enable`d, every state created from then on is tracked along with states given a name through `State::named` or `State::inspect`,
+//! counting how often and for how long their subscribers run, and the "too many nested updates" panic
+//! reports which subscriber kept updating the state and the chain of updates that led there
+//! `snapshot`, `dump` and `Overlay` show what's tracked
+
+use super::*;
+use wasm_bindgen::{prelude::*, JsCast};
+use std::{cell::Cell, collections::HashMap};
+
+pub fn enable() { ENABLED.with(|x| x.set(true)); }
+pub fn disable() { ENABLED.with(|x| x.set(false)); }
+pub fn is_enabled() -> bool { ENABLED.with(Cell::get) }
+
+/// Kept by every state, only filled in while the inspector is enabled
+#[derive(Debug, Default)]
+pub(super) struct Stats {
+	name: Option<String>,
+	updates: u64,
+	invocations: u64,
+	total_ms: f64,
+	slowest_ms: f64,
+	/// what caused the last update that happened while subscribers were already being notified
+	last_retrigger: Option<String>,
+	/// whether the state is in the registry, so that it's taken out of there when dropped
+	registered: bool,
+}
+
+type ValueFn = Box<dyn Fn() -> Option<String>>;
+
+struct Entry {
+	/// when the state was registered, to keep `snapshot` in that order
+	order: u64,
+	meta: Weak<RefCell<StateSliceMeta>>,
+	value: Option<ValueFn>,
+}
+
+/// Tracked states keyed by the address of their `StateSliceMeta`, entries are removed as the states are dropped
+#[derive(Default)]
+struct Registry {
+	next: u64,
+	entries: HashMap<*const StateSliceMeta, Entry>,
+}
+
+/// A state that's being updated, with the subscriber of it that's running if any
+struct Frame {
+	label: String,
+	subscriber: Option<SubscriptionKey>,
+}
+
+impl std::fmt::Display for Frame {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self.subscriber {
+			Some(key) => write!(f, "subscriber {:?} of `{}`", key, self.label),
+			None => write!(f, "`{}`", self.label),
+		}
+	}
+}
+
+thread_local! {
+	static ENABLED: Cell<bool> = Default::default();
+	static REGISTRY: RefCell<Registry> = Default::default();
+	static CHAIN: RefCell<Vec<Frame>> = Default::default();
+}
+
+fn label(meta: &Rc<RefCell<StateSliceMeta>>) -> String {
+	meta.borrow().debug.name.clone().unwrap_or_else(|| format!("state@{:p}", Rc::as_ptr(meta)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now_ms() -> f64 { web_sys::window().and_then(|x| x.performance()).map_or_else(js_sys::Date::now, |x| x.now()) }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_ms() -> f64 { std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|x| x.as_secs_f64() * 1000.).unwrap_or(0.) }
+
+pub(super) fn register(meta: &Rc<RefCell<StateSliceMeta>>, value: Option<ValueFn>) {
+	meta.borrow_mut().debug.registered = true;
+	REGISTRY.with(|registry| {
+		let registry = &mut *registry.borrow_mut();
+		let order = registry.next;
+		let entry = registry.entries.entry(meta.as_ptr() as *const _).or_insert_with(|| Entry { order, meta: Rc::downgrade(meta), value: None });
+		if entry.order == order { registry.next += 1; }
+		if value.is_some() { entry.value = value; }
+	});
+}
+
+/// Called when a state's `StateSliceMeta` is dropped
+pub(super) fn unregister(meta: &StateSliceMeta) {
+	if !meta.debug.registered { return; }
+	let _ = REGISTRY.try_with(|registry| if let Ok(mut registry) = registry.try_borrow_mut() { registry.entries.remove(&(meta as *const _)); });
+}
+
+pub(super) fn set_name(meta: &Rc<RefCell<StateSliceMeta>>, name: String, value: Option<ValueFn>) {
+	meta.borrow_mut().debug.name = Some(name);
+	register(meta, value);
+}
+
+/// Tracks a state's subscribers being notified, for the update chain
+pub(super) struct ChainGuard(bool);
+
+impl ChainGuard {
+	pub(super) fn enter(meta: &Rc<RefCell<StateSliceMeta>>) -> Self {
+		if !is_enabled() { return Self(false); }
+		meta.borrow_mut().debug.updates += 1;
+		let label = label(meta);
+		CHAIN.with(|chain| chain.borrow_mut().push(Frame { label, subscriber: None }));
+		Self(true)
+	}
+}
+
+impl Drop for ChainGuard {
+	fn drop(&mut self) {
+		if self.0 { CHAIN.with(|chain| chain.borrow_mut().pop()); }
+	}
+}
+
+/// Called right before a subscriber runs, returns when it started if the inspector is enabled
+pub(super) fn invoking(key: SubscriptionKey) -> Option<f64> {
+	if !is_enabled() { return None; }
+	CHAIN.with(|chain| if let Some(x) = chain.borrow_mut().last_mut() { x.subscriber = Some(key); });
+	Some(now_ms())
+}
+
+pub(super) fn invoked(meta: &Rc<RefCell<StateSliceMeta>>, started: Option<f64>) {
+	let started = if let Some(x) = started { x } else { return; };
+	let elapsed = now_ms() - started;
+	let stats = &mut meta.borrow_mut().debug;
+	stats.invocations += 1;
+	stats.total_ms += elapsed;
+	stats.slowest_ms = stats.slowest_ms.max(elapsed);
+}
+
+/// Called when a state is updated while its subscribers are being notified, which makes them run once more
+pub(super) fn retriggered(meta: &Rc<RefCell<StateSliceMeta>>) {
+	if !is_enabled() { return; }
+	let cause = CHAIN.with(|chain| chain.borrow().last().map_or_else(|| String::from("code outside of any subscriber"), ToString::to_string));
+	meta.borrow_mut().debug.last_retrigger = Some(cause);
+}
+
+pub(super) fn nested_updates_message(meta: &Rc<RefCell<StateSliceMeta>>) -> String {
+	if !is_enabled() { return String::from("too many nested updates, enable `hobo::state::inspector` to find out what caused them"); }
+	let cause = meta.borrow().debug.last_retrigger.clone().unwrap_or_else(|| String::from("unknown"));
+	let chain = CHAIN.with(|chain| chain.borrow().iter().map(ToString::to_string).collect::<Vec<_>>().join(" -> "));
+	format!("too many nested updates of `{}`, kept being updated by {}\nupdate chain: {}", label(meta), cause, chain)
+}
+
+/// What's known about a tracked state
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateInfo {
+	/// the name given through `State::named`, or an address otherwise
+	pub name: String,
+	pub subscribers: usize,
+	/// how many times subscribers were notified
+	pub updates: u64,
+	/// how many times a subscriber ran
+	pub invocations: u64,
+	pub total_ms: f64,
+	pub slowest_ms: f64,
+	/// the value if the state was registered through `State::inspect`
+	pub value: Option<String>,
+}
+
+/// All tracked states that are still alive, in the order they were registered
+pub fn snapshot() -> Vec<StateInfo> {
+	REGISTRY.with(|registry| {
+		let registry = registry.borrow();
+		let mut entries = registry.entries.values().collect::<Vec<_>>();
+		entries.sort_by_key(|x| x.order);
+		entries.into_iter().filter_map(|entry| {
+			let meta = entry.meta.upgrade()?;
+			let name = label(&meta);
+			let meta = meta.borrow();
+			Some(StateInfo {
+				name,
+				subscribers: meta.subscribers.len(),
+				updates: meta.debug.updates,
+				invocations: meta.debug.invocations,
+				total_ms: meta.debug.total_ms,
+				slowest_ms: meta.debug.slowest_ms,
+				value: entry.value.as_ref().and_then(|f| f()),
+			})
+		}).collect()
+	})
+}
+
+/// `snapshot` as a table
+pub fn report() -> String {
+	let mut report = String::from("state | subscribers | updates | invocations | total ms | slowest ms | value\n");
+	for x in snapshot() {
+		report.push_str(&format!(
+			"{} | {} | {} | {} | {:.2} | {:.2} | {}\n",
+			x.name, x.subscribers, x.updates, x.invocations, x.total_ms, x.slowest_ms, x.value.as_deref().unwrap_or("-"),
+		));
+	}
+	report
+}
+
+/// Log `report` to the console
+pub fn dump() { web_sys::console::log_1(&report().into()); }
+
+/// A panel in the corner of the page showing `report`, refreshed every second until it's dropped
+pub struct Overlay {
+	element: web_sys::HtmlElement,
+	interval: i32,
+	_refresh: Closure<dyn FnMut()>,
+}
+
+impl Overlay {
+	pub fn show() -> Self {
+		let element: web_sys::HtmlElement = crate::create::pre().into();
+		element.set_attribute(crate::web_str::style(), concat!(
+			"position: fixed; right: 0; bottom: 0; z-index: 2147483647; max-height: 50vh; overflow: auto; margin: 0; padding: 8px;",
+			"background: rgba(0, 0, 0, 0.8); color: #fff; font: 11px monospace; pointer-events: none;",
+		)).expect("can't set attribute");
+		element.set_inner_text(&report());
+		crate::dom().body().expect("no body").append_child(&element).expect("can't append child");
+
+		let refresh = Closure::wrap(Box::new({
+			let element = element.clone();
+			move || element.set_inner_text(&report())
+		}) as Box<dyn FnMut()>);
+		let interval = web_sys::window().expect("no window")
+			.set_interval_with_callback_and_timeout_and_arguments_0(refresh.as_ref().unchecked_ref(), 1000)
+			.expect("can't set interval");
+		Self { element, interval, _refresh: refresh }
+	}
+}
+
+impl Drop for Overlay {
+	fn drop(&mut self) {
+		if let Some(x) = web_sys::window() { x.clear_interval_with_handle(self.interval); }
+		self.element.remove();
+	}
+}
+
+impl<T: 'static> State<T> {
+	/// Name the state for the inspector, which also tracks it even if it was created before the inspector was enabled
+	pub fn named(self, name: impl Into<String>) -> Self {
+		set_name(&self.0.borrow().meta, name.into(), None);
+		self
+	}
+}
+
+impl<T: std::fmt::Debug + 'static> State<T> {
+	/// Same as `named`, but the value shows up in the inspector too
+	pub fn inspect(self, name: impl Into<String>) -> Self {
+		let weak = Rc::downgrade(&self.0);
+		let value = Box::new(move || weak.upgrade().and_then(|x| x.try_borrow().ok().map(|x| format!("{:?}", x.data))));
+		set_name(&self.0.borrow().meta, name.into(), Some(value));
+		self
+	}
+}
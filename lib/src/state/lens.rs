@@ -0,0 +1,120 @@
+use super::*;
+
+/// A view into a part of a `State<S>`, like a field or an element of a collection
+/// updating through a lens notifies all subscribers of the state as usual,
+/// but subscribers of the lens only fire when the part it's focused on is different afterwards
+/// `#[derive(hobo::Lenses)]` on a struct generates accessors for its fields
+/// lenses made by `at` can lose their target when the `Vec` shrinks, see `try_view`
+pub struct Lens<S, T> {
+	state: State<S>,
+	get: Getter<S, T>,
+	get_mut: GetterMut<S, T>,
+}
+
+type Getter<S, T> = Rc<dyn Fn(&S) -> Option<&T>>;
+type GetterMut<S, T> = Rc<dyn Fn(&mut S) -> Option<&mut T>>;
+
+impl<S, T> Clone for Lens<S, T> {
+	fn clone(&self) -> Self { Self { state: self.state.clone(), get: Rc::clone(&self.get), get_mut: Rc::clone(&self.get_mut) } }
+}
+
+struct LensGuard<'a, S, T> {
+	guard: StateGuard<'a, S>,
+	get: &'a dyn Fn(&S) -> Option<&T>,
+	get_mut: &'a dyn Fn(&mut S) -> Option<&mut T>,
+}
+
+impl<'a, S, T> Deref for LensGuard<'a, S, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target { (self.get)(&self.guard).expect("lens target is gone") }
+}
+
+impl<'a, S, T> DerefMut for LensGuard<'a, S, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target { (self.get_mut)(&mut self.guard).expect("lens target is gone") }
+}
+
+impl<S: 'static, T: 'static> Lens<S, T> {
+	pub fn new(state: &State<S>, get: impl Fn(&S) -> &T + 'static, get_mut: impl Fn(&mut S) -> &mut T + 'static) -> Self {
+		Self::new_fallible(state, move |x| Some(get(x)), move |x| Some(get_mut(x)))
+	}
+
+	/// A lens whose target might not be there, like an element of a collection that can shrink
+	pub fn new_fallible(state: &State<S>, get: impl Fn(&S) -> Option<&T> + 'static, get_mut: impl Fn(&mut S) -> Option<&mut T> + 'static) -> Self {
+		Self { state: state.clone(), get: Rc::new(get), get_mut: Rc::new(get_mut) }
+	}
+
+	/// The whole state this lens is focused into
+	pub fn state(&self) -> &State<S> { &self.state }
+
+	/// Panics if the target is gone, see `try_view`
+	pub fn view<'a>(&'a self) -> impl Deref<Target = T> + 'a { Ref::map(self.state.0.borrow(), |x| (self.get)(&x.data).expect("lens target is gone")) }
+
+	/// `None` if the target is gone, e.g. the element of an `at` lens was removed
+	pub fn try_view<'a>(&'a self) -> Option<impl Deref<Target = T> + 'a> {
+		(self.get)(&self.state.view())?;
+		Some(self.view())
+	}
+
+	/// Panics if the target is gone
+	pub fn update<'a>(&'a self) -> impl DerefMut<Target = T> + 'a {
+		LensGuard { guard: StateGuard { state: Some(self.state.0.borrow_mut()) }, get: &*self.get, get_mut: &*self.get_mut }
+	}
+
+	/// Subscribe to changes of the part this lens is focused on
+	/// a snapshot of it is kept to compare against whenever the state is updated,
+	/// and updates are skipped while the target is gone
+	#[must_use]
+	pub fn subscribe(&self, mut f: impl FnMut() + 'static) -> Subscription where T: PartialEq + Clone {
+		let mut last = self.try_view().map(|x| (*x).clone());
+		let state = Rc::downgrade(&self.state.0);
+		let get = Rc::clone(&self.get);
+		self.state.subscribe(move || {
+			let state = if let Some(x) = state.upgrade() { x } else { return; };
+			{
+				let state = state.borrow();
+				let current = if let Some(x) = get(&state.data) { x } else { return; };
+				if matches!(&last, Some(x) if x == current) { return; }
+				last = Some(current.clone());
+			}
+			f();
+		})
+	}
+}
+
+impl<S: 'static, T: 'static> Lens<S, Vec<T>> {
+	/// Focus on an element, which is gone while the `Vec` is too short
+	pub fn at(&self, index: usize) -> Lens<S, T> {
+		let (get, get_mut) = (Rc::clone(&self.get), Rc::clone(&self.get_mut));
+		Lens::new_fallible(&self.state, move |x| get(x)?.get(index), move |x| get_mut(x)?.get_mut(index))
+	}
+}
+
+impl<T: 'static> State<Vec<T>> {
+	/// Focus on an element, which is gone while the `Vec` is too short
+	pub fn at(&self, index: usize) -> Lens<Vec<T>, T> { Lens::new_fallible(self, move |x| x.get(index), move |x| x.get_mut(index)) }
+}
+
+/// Anything that can be narrowed down into a `Lens`, implemented by `State` and `Lens` itself
+pub trait Focus<T> {
+	type Root: 'static;
+
+	fn focus<U: 'static>(&self, get: impl Fn(&T) -> &U + 'static, get_mut: impl Fn(&mut T) -> &mut U + 'static) -> Lens<Self::Root, U>;
+}
+
+impl<T: 'static> Focus<T> for State<T> {
+	type Root = T;
+
+	fn focus<U: 'static>(&self, get: impl Fn(&T) -> &U + 'static, get_mut: impl Fn(&mut T) -> &mut U + 'static) -> Lens<T, U> {
+		Lens::new(self, get, get_mut)
+	}
+}
+
+impl<S: 'static, T: 'static> Focus<T> for Lens<S, T> {
+	type Root = S;
+
+	fn focus<U: 'static>(&self, get: impl Fn(&T) -> &U + 'static, get_mut: impl Fn(&mut T) -> &mut U + 'static) -> Lens<S, U> {
+		let (outer_get, outer_get_mut) = (Rc::clone(&self.get), Rc::clone(&self.get_mut));
+		Lens::new_fallible(&self.state, move |x| outer_get(x).map(&get), move |x| outer_get_mut(x).map(&get_mut))
+	}
+}
@@ -0,0 +1,239 @@
+use super::*;
+use crate::{web_str, EventHandler, ListenerOptions};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{cell::Cell, collections::HashMap};
+
+/// Where persisted states are kept, see `Persist`
+pub trait Storage {
+	fn get(&self, key: &str) -> Option<String>;
+	fn set(&self, key: &str, value: &str);
+	fn remove(&self, key: &str);
+
+	/// Call `f` with the new value whenever `key` is changed by someone else, e.g. a different tab
+	/// the returned handler stops listening when dropped, storages that can't be changed externally return `None`
+	fn watch(&self, _key: &str, _f: Box<dyn FnMut(Option<String>)>) -> Option<EventHandler> { None }
+}
+
+/// `localStorage` or `sessionStorage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebStorage {
+	Local,
+	Session,
+}
+
+impl WebStorage {
+	fn storage(self) -> web_sys::Storage {
+		let window = web_sys::window().expect("no window");
+		match self {
+			Self::Local => window.local_storage(),
+			Self::Session => window.session_storage(),
+		}.expect("can't access storage").expect("no storage")
+	}
+}
+
+impl Storage for WebStorage {
+	fn get(&self, key: &str) -> Option<String> { self.storage().get_item(key).ok().flatten() }
+	// writes can fail once the quota is exceeded, in which case the value just isn't persisted
+	fn set(&self, key: &str, value: &str) { let _ = self.storage().set_item(key, value); }
+	fn remove(&self, key: &str) { let _ = self.storage().remove_item(key); }
+
+	fn watch(&self, key: &str, mut f: Box<dyn FnMut(Option<String>)>) -> Option<EventHandler> {
+		let window: web_sys::EventTarget = web_sys::window().expect("no window").into();
+		let (key, storage) = (key.to_owned(), self.storage());
+		Some(EventHandler::new(&window, web_str::storage(), ListenerOptions::default(), move |event: web_sys::StorageEvent| {
+			// `key` is null when the whole storage is cleared, which is ignored
+			if event.key().as_deref() != Some(key.as_str()) { return; }
+			if event.storage_area().as_ref() != Some(&storage) { return; }
+			f(event.new_value());
+		}))
+	}
+}
+
+type Watcher = Rc<RefCell<dyn FnMut(Option<String>)>>;
+
+/// A `Storage` that's just a map, for tests and for running outside of a browser
+/// clones share the same contents
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+	items: Rc<RefCell<HashMap<String, String>>>,
+	watchers: Rc<RefCell<DenseSlotMap<SubscriptionKey, (String, Watcher)>>>,
+}
+
+impl MemoryStorage {
+	pub fn new() -> Self { Self::default() }
+
+	/// Change a value as if it was done by a different tab, notifying watchers
+	pub fn set_external(&self, key: &str, value: Option<&str>) {
+		match value {
+			Some(value) => self.set(key, value),
+			None => self.remove(key),
+		}
+		let watchers = self.watchers.borrow().values().filter(|(x, _)| x == key).map(|(_, f)| Rc::clone(f)).collect::<Vec<_>>();
+		for watcher in watchers {
+			(*watcher.borrow_mut())(value.map(String::from));
+		}
+	}
+}
+
+impl Storage for MemoryStorage {
+	fn get(&self, key: &str) -> Option<String> { self.items.borrow().get(key).cloned() }
+	fn set(&self, key: &str, value: &str) { self.items.borrow_mut().insert(key.to_owned(), value.to_owned()); }
+	fn remove(&self, key: &str) { self.items.borrow_mut().remove(key); }
+
+	fn watch(&self, key: &str, f: Box<dyn FnMut(Option<String>)>) -> Option<EventHandler> {
+		let id = self.watchers.borrow_mut().insert((key.to_owned(), Rc::new(RefCell::new(f))));
+		let watchers = Rc::downgrade(&self.watchers);
+		Some(EventHandler::from_cleanup(move || if let Some(x) = watchers.upgrade() { x.borrow_mut().remove(id); }))
+	}
+}
+
+type Migration = Rc<dyn Fn(u32, Value) -> Option<Value>>;
+
+/// What's made of a saved value
+enum Loaded<T> {
+	Current(T),
+	Migrated(T),
+	/// saved by a newer version, which this one can't make sense of but shouldn't throw away either
+	Newer,
+	/// can't be parsed, migrated or deserialized
+	Unusable,
+}
+
+/// Options for a `State` that's loaded from and saved into a `Storage`, `State::persisted` covers the common case
+/// values are stored as JSON along with a version, so that values saved by an older version can be migrated
+/// ```rust,ignore
+/// let settings = state::Persist::new("settings")
+///     .version(2)
+///     .migrate(|from, value| if from == 1 { Some(json!({ "theme": value["dark"].as_bool()?.then(|| "dark") })) } else { None })
+///     .sync_tabs(true)
+///     .build(Settings::default());
+/// ```
+pub struct Persist {
+	key: String,
+	storage: Rc<dyn Storage>,
+	debounce: i32,
+	version: u32,
+	migrate: Option<Migration>,
+	sync_tabs: bool,
+}
+
+impl Persist {
+	/// Saves into `localStorage` at most every 100ms, without migrations or syncing
+	pub fn new(key: impl Into<String>) -> Self {
+		Self { key: key.into(), storage: Rc::new(WebStorage::Local), debounce: 100, version: 0, migrate: None, sync_tabs: false }
+	}
+
+	pub fn storage(mut self, storage: impl Storage + 'static) -> Self { self.storage = Rc::new(storage); self }
+	pub fn session(self) -> Self { self.storage(WebStorage::Session) }
+
+	/// Wait until there have been no updates for `ms` milliseconds before saving, 0 saves on every update
+	/// a save that's still pending when the state is dropped happens right away
+	pub fn debounce(mut self, ms: i32) -> Self { self.debounce = ms; self }
+
+	/// The version saved values are tagged with, values without one are version 0
+	pub fn version(mut self, version: u32) -> Self { self.version = version; self }
+
+	/// Turn a value saved by an older version into one that deserializes as the current one, `None` discards it
+	pub fn migrate(mut self, f: impl Fn(u32, Value) -> Option<Value> + 'static) -> Self { self.migrate = Some(Rc::new(f)); self }
+
+	/// Pick up changes made in other tabs through the `storage` event
+	pub fn sync_tabs(mut self, sync_tabs: bool) -> Self { self.sync_tabs = sync_tabs; self }
+
+	/// Parse a saved value, migrating it if needed
+	fn load<T: DeserializeOwned>(&self, raw: &str) -> Loaded<T> {
+		let parse = || {
+			let (version, mut value) = match serde_json::from_str::<Value>(raw).ok()? {
+				Value::Object(mut x) if x.len() == 2 && x.contains_key("version") && x.contains_key("value") => (x["version"].as_u64()? as u32, x.remove("value")?),
+				x => (0, x),
+			};
+			if version > self.version { return Some(Loaded::Newer); }
+			if version < self.version { value = (self.migrate.as_ref()?)(version, value)?; }
+			let value = serde_json::from_value(value).ok()?;
+			Some(if version == self.version { Loaded::Current(value) } else { Loaded::Migrated(value) })
+		};
+		parse().unwrap_or(Loaded::Unusable)
+	}
+
+	fn save<T: Serialize>(&self, value: &T) -> Option<String> {
+		serde_json::to_string(&serde_json::json!({ "version": self.version, "value": serde_json::to_value(value).ok()? })).ok()
+	}
+
+	/// Create the state, loading it from storage if there's a usable value there and falling back to `default` otherwise
+	/// values that can't be parsed or migrated are removed, values saved by a newer version are kept until the state is updated
+	pub fn build<T: Serialize + DeserializeOwned + 'static>(self, default: T) -> State<T> {
+		let initial = match self.storage.get(&self.key).map(|raw| self.load(&raw)) {
+			None | Some(Loaded::Newer) => default,
+			Some(Loaded::Current(value)) => value,
+			Some(Loaded::Migrated(value)) => {
+				if let Some(x) = self.save(&value) { self.storage.set(&self.key, &x); }
+				value
+			},
+			Some(Loaded::Unusable) => {
+				self.storage.remove(&self.key);
+				default
+			},
+		};
+		let state = State::new(initial);
+		let this = Rc::new(self);
+
+		// set while applying a change from another tab, so it isn't saved right back
+		let syncing = Rc::new(Cell::new(false));
+		let watcher = if this.sync_tabs {
+			let (storage, key) = (Rc::clone(&this.storage), this.key.clone());
+			let (this, weak, syncing) = (Rc::clone(&this), Rc::downgrade(&state.0), Rc::clone(&syncing));
+			storage.watch(&key, Box::new(move |raw| {
+				let state = if let Some(x) = weak.upgrade() { x } else { return; };
+				let value = match raw.map(|raw| this.load::<T>(&raw)) {
+					Some(Loaded::Current(x)) | Some(Loaded::Migrated(x)) => x,
+					_ => return,
+				};
+				state.borrow_mut().data = value;
+				let meta = Rc::clone(&state.borrow().meta);
+				syncing.set(true);
+				StateSliceMeta::trigger_update(&meta);
+				syncing.set(false);
+			}))
+		} else { None };
+
+		let saver = Rc::new(Saver { persist: Rc::clone(&this), pending: RefCell::new(None), _watcher: watcher });
+		let mut write: Box<dyn FnMut(())> = {
+			let saver = Rc::clone(&saver);
+			if this.debounce > 0 { Box::new(crate::debounce(this.debounce, move |_: ()| saver.flush())) } else { Box::new(move |_| saver.flush()) }
+		};
+
+		let weak = Rc::downgrade(&state.0);
+		state.subscribe_key(move || {
+			if syncing.get() { return; }
+			let state = if let Some(x) = weak.upgrade() { x } else { return; };
+			let serialized = this.save(&state.borrow().data);
+			*saver.pending.borrow_mut() = serialized;
+			write(());
+		});
+
+		state
+	}
+}
+
+/// Lives as long as the state, holds the latest serialized value that hasn't been written yet and writes it out when dropped
+struct Saver {
+	persist: Rc<Persist>,
+	pending: RefCell<Option<String>>,
+	_watcher: Option<EventHandler>,
+}
+
+impl Saver {
+	fn flush(&self) {
+		let value = self.pending.borrow_mut().take();
+		if let Some(x) = value { self.persist.storage.set(&self.persist.key, &x); }
+	}
+}
+
+impl Drop for Saver {
+	fn drop(&mut self) { self.flush(); }
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> State<T> {
+	/// A state that's saved into `localStorage` under `key` and loaded from it on creation, see `Persist` for more options
+	pub fn persisted(key: impl Into<String>, default: T) -> Self { Persist::new(key).build(default) }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use super::inspector;
 
 static MAX_NESTED_UPDATES: usize = 100;
 type SubscriptionFn = Rc<RefCell<dyn FnMut()>>;
@@ -8,29 +9,50 @@ pub(super) struct StateSliceMeta {
 	pub(super) subscribers: DenseSlotMap<SubscriptionKey, SubscriptionFn>,
 	pub(super) update_ongoing: bool,
 	pub(super) dirty: bool,
+	pub(super) debug: super::inspector::Stats,
 }
 
 impl StateSliceMeta {
 	pub(super) fn trigger_update(this: &Rc<RefCell<Self>>) {
+		if super::batch::defer(this) { return; }
 		this.borrow_mut().dirty = true;
-		if this.borrow().update_ongoing { return; }
+		if this.borrow().update_ongoing {
+			inspector::retriggered(this);
+			return;
+		}
 		this.borrow_mut().update_ongoing = true;
+		let _ongoing = UpdateGuard(this);
+		let _chain = inspector::ChainGuard::enter(this);
 
 		for _ in 0..MAX_NESTED_UPDATES {
 			this.borrow_mut().dirty = false;
-			let snapshot = this.borrow().subscribers.values().cloned().collect::<Vec<_>>();
-			for subscriber in snapshot {
+			let snapshot = this.borrow().subscribers.iter().map(|(key, x)| (key, Rc::clone(x))).collect::<Vec<_>>();
+			for (key, subscriber) in snapshot {
+				let started = inspector::invoking(key);
 				let subscriber = &mut *subscriber.borrow_mut();
 				subscriber();
+				inspector::invoked(this, started);
 			}
 
-			if !this.borrow().dirty {
-				this.borrow_mut().update_ongoing = false;
-				return;
-			}
+			if !this.borrow().dirty { return; }
 		}
 
-		panic!("too many nested updates");
+		panic!("{}", inspector::nested_updates_message(this));
+	}
+}
+
+impl Drop for StateSliceMeta {
+	fn drop(&mut self) { inspector::unregister(self); }
+}
+
+/// Marks the update as done even if a subscriber panics, so that the state's subscribers keep being notified afterwards
+struct UpdateGuard<'a>(&'a Rc<RefCell<StateSliceMeta>>);
+
+impl Drop for UpdateGuard<'_> {
+	fn drop(&mut self) {
+		let mut meta = self.0.borrow_mut();
+		meta.update_ongoing = false;
+		meta.dirty = false;
 	}
 }
 
@@ -42,7 +64,9 @@ pub struct StateSlice<T> {
 
 impl<T> StateSlice<T> {
 	pub fn new(initial: T) -> Self {
-		Self { data: initial, meta: Default::default() }
+		let meta = Default::default();
+		if inspector::is_enabled() { inspector::register(&meta, None); }
+		Self { data: initial, meta }
 	}
 
 	pub fn update<'a>(&'a mut self) -> impl DerefMut<Target = T> + 'a {
@@ -57,7 +81,7 @@ impl<T> StateSlice<T> {
 
 	#[must_use]
 	pub fn subscribe(&self, f: impl FnMut() + 'static) -> Subscription {
-		Subscription(Rc::downgrade(&self.meta), self.subscribe_key(f))
+		Subscription(Rc::downgrade(&self.meta) as Weak<dyn Unsubscribe>, self.subscribe_key(f))
 	}
 
 	pub fn unsubscribe(&self, key: SubscriptionKey) { self.meta.borrow_mut().subscribers.remove(key); }
@@ -0,0 +1,130 @@
+use super::*;
+use std::{cell::Cell, collections::VecDeque, future::Future};
+
+/// Hooks into every action dispatched to a `Store`
+/// `State::persisted` or `Persist::build` can be handed to `Store::with_state` for persistence,
+/// `after` is the place for anything that has to see the model once an action has been applied
+pub trait Middleware<M, A> {
+	/// Called before the reducer, the action that's returned is passed on, `None` drops it
+	fn before(&self, _store: &Store<M, A>, action: A) -> Option<A> { Some(action) }
+
+	/// Called after the reducer, subscribers of the store have already been notified by then
+	fn after(&self, _store: &Store<M, A>, _action: &A, _model: &M) {}
+}
+
+/// Logs every action along with the resulting model to the console
+pub struct Logger;
+
+impl<M: std::fmt::Debug, A: std::fmt::Debug> Middleware<M, A> for Logger {
+	fn after(&self, _store: &Store<M, A>, action: &A, model: &M) {
+		web_sys::console::log_1(&format!("{:?} -> {:#?}", action, model).into());
+	}
+}
+
+/// Runs the future `f` returns for an action, e.g. to fetch something and dispatch the result
+/// ```rust,ignore
+/// store.with_middleware(Effect(|action: &Action, store: &Store<Model, Action>| match action {
+///     Action::Load(id) => {
+///         let (store, id) = (store.clone(), *id);
+///         Some(async move { store.dispatch(Action::Loaded(fetch_item(id).await)) })
+///     },
+///     _ => None,
+/// }))
+/// ```
+pub struct Effect<F>(pub F);
+
+impl<M: 'static, A: 'static, F, Fut> Middleware<M, A> for Effect<F> where
+	F: Fn(&A, &Store<M, A>) -> Option<Fut>,
+	Fut: Future<Output = ()> + 'static,
+{
+	fn after(&self, store: &Store<M, A>, action: &A, _model: &M) {
+		if let Some(x) = (self.0)(action, store) { wasm_bindgen_futures::spawn_local(x); }
+	}
+}
+
+type Reducer<M, A> = Box<dyn Fn(&mut M, A)>;
+type Hooks<M, A> = Vec<Rc<dyn Middleware<M, A>>>;
+
+struct StoreInner<M, A> {
+	state: State<M>,
+	reducer: Reducer<M, A>,
+	middleware: RefCell<Hooks<M, A>>,
+	queue: RefCell<VecDeque<A>>,
+	dispatching: Cell<bool>,
+}
+
+/// A `State` that's only changed by dispatching actions, which a reducer applies to it
+/// actions dispatched while another one is being handled, e.g. from a subscriber, are handled right after it
+/// ```rust,ignore
+/// let store = Store::new(Model::default(), |model: &mut Model, action| match action {
+///     Action::Increment => model.count += 1,
+/// });
+/// button.on_click(enclose!((store) move |_| store.dispatch(Action::Increment)));
+/// ```
+pub struct Store<M, A>(Rc<StoreInner<M, A>>);
+
+impl<M, A> Clone for Store<M, A> {
+	fn clone(&self) -> Self { Self(Rc::clone(&self.0)) }
+}
+
+impl<M: 'static, A: Clone + 'static> Store<M, A> {
+	pub fn new(model: M, reducer: impl Fn(&mut M, A) + 'static) -> Self { Self::with_state(State::new(model), reducer) }
+
+	pub fn with_state(state: State<M>, reducer: impl Fn(&mut M, A) + 'static) -> Self {
+		Self(Rc::new(StoreInner {
+			state,
+			reducer: Box::new(reducer),
+			middleware: Default::default(),
+			queue: Default::default(),
+			dispatching: Cell::new(false),
+		}))
+	}
+
+	/// Middleware runs in the order it's added
+	pub fn with_middleware(self, middleware: impl Middleware<M, A> + 'static) -> Self { self.add_middleware(middleware); self }
+	pub fn add_middleware(&self, middleware: impl Middleware<M, A> + 'static) { self.0.middleware.borrow_mut().push(Rc::new(middleware)); }
+
+	/// The state holding the model, anything built around `State` can work with it, but it should only be changed through `dispatch`
+	pub fn state(&self) -> &State<M> { &self.0.state }
+	pub fn view<'a>(&'a self) -> impl Deref<Target = M> + 'a { self.0.state.view() }
+
+	#[must_use]
+	pub fn subscribe(&self, f: impl FnMut() + 'static) -> Subscription { self.0.state.subscribe(f) }
+
+	/// if the reducer or middleware panics, actions still queued up are dropped and the store keeps working
+	pub fn dispatch(&self, action: A) {
+		self.0.queue.borrow_mut().push_back(action);
+		if self.0.dispatching.replace(true) { return; }
+		let _dispatching = Dispatching(&self.0);
+		loop {
+			let action = if let Some(x) = self.0.queue.borrow_mut().pop_front() { x } else { break; };
+			self.handle(action);
+		}
+	}
+
+	fn handle(&self, mut action: A) {
+		let middleware = self.0.middleware.borrow().clone();
+		for hook in &middleware {
+			action = if let Some(x) = hook.before(self, action) { x } else { return; };
+		}
+
+		if middleware.is_empty() {
+			(self.0.reducer)(&mut self.0.state.update(), action);
+			return;
+		}
+
+		(self.0.reducer)(&mut self.0.state.update(), action.clone());
+		for hook in &middleware {
+			hook.after(self, &action, &self.0.state.view());
+		}
+	}
+}
+
+struct Dispatching<'a, M, A>(&'a StoreInner<M, A>);
+
+impl<M, A> Drop for Dispatching<'_, M, A> {
+	fn drop(&mut self) {
+		if std::thread::panicking() { self.0.queue.borrow_mut().clear(); }
+		self.0.dispatching.set(false);
+	}
+}
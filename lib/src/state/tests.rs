@@ -51,3 +51,394 @@ fn sub_drop() {
 	*state.update() = 10;
 	assert_eq!(*state.view(), 10);
 }
+
+#[test]
+fn derived_map() {
+	let state = State::new(5);
+	let parity = state.map(|x| x % 2);
+	let runs = Rc::new(RefCell::new(0));
+	let sub = parity.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+
+	*state.update() = 7;
+	assert_eq!(*runs.borrow(), 0);
+	*state.update() = 8;
+	assert_eq!(*runs.borrow(), 1);
+	assert_eq!(*parity.view(), 0);
+
+	drop(sub);
+	*state.update() = 9;
+	assert_eq!(*runs.borrow(), 1);
+	assert_eq!(*parity.view(), 1);
+}
+
+#[test]
+fn derived_combine() {
+	let a = State::new(2);
+	let b = State::new(3);
+	let sum = State::combine((a.clone(), b.clone()), |a: &i32, b: &i32| a + b);
+	let doubled = sum.map(|x| x * 2);
+	assert_eq!(*doubled.view(), 10);
+
+	*a.update() = 4;
+	assert_eq!(*sum.view(), 7);
+	assert_eq!(*doubled.view(), 14);
+
+	let computed = Rc::new(RefCell::new(0));
+	let lazy = State::combine((a.clone(),), crate::enclose!((computed) move |a: &i32| { *computed.borrow_mut() += 1; *a }));
+	*a.update() = 5;
+	*a.update() = 6;
+	assert_eq!(*computed.borrow(), 1);
+	assert_eq!(*lazy.view(), 6);
+	assert_eq!(*computed.borrow(), 2);
+}
+
+#[test]
+fn derived_drop_unsubscribes() {
+	let state = State::new(1);
+	let derived = state.map(|x| x + 1);
+	assert_eq!(state.0.borrow().meta.borrow().subscribers.len(), 1);
+	drop(derived);
+	assert_eq!(state.0.borrow().meta.borrow().subscribers.len(), 0);
+}
+
+#[test]
+fn lens_subscribers() {
+	#[derive(Default)]
+	struct Model {
+		name: String,
+		items: Vec<i32>,
+	}
+
+	let state = State::new(Model { name: "a".to_owned(), items: vec![1, 2] });
+	let name = state.focus(|x| &x.name, |x| &mut x.name);
+	let second = state.focus(|x| &x.items, |x| &mut x.items).at(1);
+	let (name_runs, second_runs) = (Rc::new(RefCell::new(0)), Rc::new(RefCell::new(0)));
+	let _name_sub = name.subscribe(crate::enclose!((name_runs) move || *name_runs.borrow_mut() += 1));
+	let _second_sub = second.subscribe(crate::enclose!((second_runs) move || *second_runs.borrow_mut() += 1));
+
+	*second.update() = 5;
+	assert_eq!((*name_runs.borrow(), *second_runs.borrow()), (0, 1));
+	assert_eq!(state.view().items, vec![1, 5]);
+
+	state.update().name.push('b');
+	assert_eq!((*name_runs.borrow(), *second_runs.borrow()), (1, 1));
+	assert_eq!(*name.view(), "ab");
+
+	state.update().items[0] = 10;
+	assert_eq!((*name_runs.borrow(), *second_runs.borrow()), (1, 1));
+}
+
+#[test]
+fn batched_updates() {
+	let (a, b) = (State::new(1), State::new(2));
+	let runs = Rc::new(RefCell::new(0));
+	let _sub_a = a.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+	let _sub_b = b.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+
+	let res = batch(|| {
+		*a.update() = 10;
+		*a.update() = 11;
+		batch(|| *b.update() = 20);
+		assert_eq!(*runs.borrow(), 0);
+		*a.view() + *b.view()
+	});
+	assert_eq!(res, 31);
+	assert_eq!(*runs.borrow(), 2);
+
+	*a.update_silent() = 12;
+	assert_eq!(*runs.borrow(), 2);
+	assert_eq!(*a.view(), 12);
+}
+
+#[test]
+fn batch_survives_panicking_subscriber() {
+	let (a, b) = (State::new(1), State::new(2));
+	let runs = Rc::new(RefCell::new(0));
+	let a_runs = Rc::new(RefCell::new(0));
+	let _sub_a = a.subscribe(crate::enclose!((a_runs) move || {
+		*a_runs.borrow_mut() += 1;
+		if *a_runs.borrow() == 1 { panic!("subscriber failed"); }
+	}));
+	let _sub_b = b.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+
+	let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| batch(|| {
+		*a.update() = 10;
+		*b.update() = 20;
+	})));
+	assert!(res.is_err());
+	assert_eq!(*runs.borrow(), 0);
+
+	batch(|| {});
+	assert_eq!(*runs.borrow(), 1);
+	*b.update() = 21;
+	assert_eq!(*runs.borrow(), 2);
+
+	*a.update() = 11;
+	assert_eq!(*a_runs.borrow(), 2);
+
+	let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| batch(|| {
+		*b.update() = 22;
+		panic!("batch failed");
+	})));
+	assert!(res.is_err());
+	assert_eq!(*runs.borrow(), 2);
+	batch(|| {});
+	assert_eq!(*runs.borrow(), 3);
+}
+
+#[test]
+fn change_detection() {
+	let state = State::new(1);
+	let changes = Rc::new(RefCell::new(Vec::new()));
+	let _sub = state.subscribe_changes(crate::enclose!((changes) move |old: &i32, new: &i32| changes.borrow_mut().push((*old, *new))));
+
+	*state.update_if_changed() = 1;
+	*state.update() = 1;
+	*state.update_if_changed() = 2;
+	*state.update() = 3;
+	assert_eq!(*changes.borrow(), vec![(1, 2), (2, 3)]);
+
+	// a subscriber that keeps writing the same value settles instead of hitting the nested update limit
+	let _clamp = state.subscribe(crate::enclose!((state) move || {
+		let clamped = (*state.view()).min(10);
+		*state.update_if_changed() = clamped;
+	}));
+	*state.update() = 20;
+	assert_eq!(*state.view(), 10);
+	assert_eq!(changes.borrow()[2..], [(3, 20), (20, 10)]);
+}
+
+#[test]
+fn state_vec_diffs() {
+	let list = StateVec::new(vec!["a", "b", "c"]);
+	let diffs = Rc::new(RefCell::new(Vec::new()));
+	let runs = Rc::new(RefCell::new(0));
+	let _diffs = list.subscribe_diffs(crate::enclose!((diffs) move |diff: &VecDiff<&'static str>| diffs.borrow_mut().push(diff.clone())));
+	let _runs = list.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+
+	list.push("d");
+	list.move_item(3, 0);
+	assert_eq!(list.remove(1), "a");
+	assert_eq!(*list.view(), vec!["d", "b", "c"]);
+	assert_eq!(*diffs.borrow(), vec![
+		VecDiff::Insert { index: 3, value: "d" },
+		VecDiff::Move { from: 3, to: 0 },
+		VecDiff::Remove { index: 1 },
+	]);
+	assert_eq!(*runs.borrow(), 3);
+
+	diffs.borrow_mut().clear();
+	list.reconcile(vec!["c", "e", "d"], |x| *x);
+	assert_eq!(*list.view(), vec!["c", "e", "d"]);
+	assert_eq!(*diffs.borrow(), vec![
+		VecDiff::Remove { index: 1 },
+		VecDiff::Move { from: 1, to: 0 },
+		VecDiff::Insert { index: 1, value: "e" },
+	]);
+	assert_eq!(*runs.borrow(), 4);
+
+	let map = StateMap::default();
+	let map_diffs = Rc::new(RefCell::new(Vec::new()));
+	let _map_diffs = map.subscribe_diffs(crate::enclose!((map_diffs) move |diff: &MapDiff<u32, u32>| map_diffs.borrow_mut().push(diff.clone())));
+	map.insert(1, 10);
+	map.insert(1, 11);
+	assert!(map.update_at(&1, |x| *x += 1));
+	assert_eq!(map.remove(&2), None);
+	assert_eq!(map.remove(&1), Some(12));
+	assert_eq!(*map_diffs.borrow(), vec![
+		MapDiff::Insert { key: 1, value: 10 },
+		MapDiff::Update { key: 1, value: 11 },
+		MapDiff::Update { key: 1, value: 12 },
+		MapDiff::Remove { key: 1 },
+	]);
+}
+
+#[test]
+fn persisted_state() {
+	let storage = MemoryStorage::new();
+	let persist = || Persist::new("count").storage(storage.clone()).debounce(0);
+
+	let count = persist().build(1_u32);
+	assert_eq!(storage.get("count"), None);
+	*count.update() = 2;
+	assert_eq!(storage.get("count").as_deref(), Some(r#"{"value":2,"version":0}"#));
+	assert_eq!(*persist().build(0_u32).view(), 2);
+
+	// corrupt values are dropped
+	storage.set("count", "not json");
+	assert_eq!(*persist().build(5_u32).view(), 5);
+	assert_eq!(storage.get("count"), None);
+
+	// values from a newer version are left alone
+	storage.set("count", r#"{"value":"new","version":7}"#);
+	assert_eq!(*persist().version(1).build(5_u32).view(), 5);
+	assert_eq!(storage.get("count").as_deref(), Some(r#"{"value":"new","version":7}"#));
+
+	// unversioned values are version 0 and get migrated and saved back
+	storage.set("count", "3");
+	let migrated = persist().version(1).migrate(|from, value| if from == 0 { Some((value.as_u64()? * 10).into()) } else { None }).build(0_u32);
+	assert_eq!(*migrated.view(), 30);
+	assert_eq!(storage.get("count").as_deref(), Some(r#"{"value":30,"version":1}"#));
+
+	let synced = persist().version(1).sync_tabs(true).build(0_u32);
+	let runs = Rc::new(RefCell::new(0));
+	let _sub = synced.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+	storage.set_external("count", Some(r#"{"value":40,"version":1}"#));
+	assert_eq!((*synced.view(), *runs.borrow()), (40, 1));
+	storage.set_external("count", Some("garbage"));
+	assert_eq!((*synced.view(), *runs.borrow()), (40, 1));
+}
+
+#[test]
+fn undo_redo() {
+	let state = State::new(0);
+	let history = History::new(state.clone()).with_limit(3);
+	assert!(!*history.can_undo().view());
+
+	*state.update() = 1;
+	history.group(|| {
+		*state.update() = 2;
+		*state.update() = 3;
+	});
+	*state.update_if_changed() = 3;
+	assert!(*history.can_undo().view());
+
+	assert!(history.undo());
+	assert_eq!(*state.view(), 1);
+	assert!(*history.can_redo().view());
+	assert!(history.redo());
+	assert_eq!(*state.view(), 3);
+
+	history.undo();
+	*state.update() = 10;
+	assert!(!history.redo());
+	assert!(!*history.can_redo().view());
+
+	for i in 11..15 { *state.update() = i; }
+	while history.undo() {}
+	assert_eq!(*state.view(), 11);
+
+	struct Push(u32);
+	impl Patch<Vec<u32>> for Push {
+		fn apply(&self, value: &mut Vec<u32>) { value.push(self.0); }
+		fn revert(&self, value: &mut Vec<u32>) { value.pop(); }
+	}
+	let list = History::with_patches(State::new(vec![1]));
+	list.apply(Push(2));
+	list.apply(Push(3));
+	list.undo();
+	assert_eq!(*list.state().view(), vec![1, 2]);
+	list.redo();
+	assert_eq!(*list.state().view(), vec![1, 2, 3]);
+}
+
+#[test]
+fn store_dispatch() {
+	#[derive(Clone, Debug, PartialEq)]
+	enum Action { Add(i32), Double, Ignored }
+
+	struct Recorder(Rc<RefCell<Vec<(Action, i32)>>>);
+	impl Middleware<i32, Action> for Recorder {
+		fn before(&self, _store: &Store<i32, Action>, action: Action) -> Option<Action> {
+			if action == Action::Ignored { None } else { Some(action) }
+		}
+
+		fn after(&self, _store: &Store<i32, Action>, action: &Action, model: &i32) { self.0.borrow_mut().push((action.clone(), *model)); }
+	}
+
+	let log = Rc::new(RefCell::new(Vec::new()));
+	let store = Store::new(1, |model: &mut i32, action| match action {
+		Action::Add(x) => *model += x,
+		Action::Double => *model *= 2,
+		Action::Ignored => unreachable!(),
+	}).with_middleware(Recorder(Rc::clone(&log)));
+
+	// actions dispatched from subscribers are handled after the current one
+	let _sub = store.subscribe(crate::enclose!((store) move || if *store.view() == 3 { store.dispatch(Action::Double); }));
+	store.dispatch(Action::Add(2));
+	store.dispatch(Action::Ignored);
+	assert_eq!(*store.view(), 6);
+	assert_eq!(*log.borrow(), vec![(Action::Add(2), 3), (Action::Double, 6)]);
+
+	let fragile = Store::new(0, |model: &mut i32, x: i32| if x < 0 { panic!("negative") } else { *model += x });
+	assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| fragile.dispatch(-1))).is_err());
+	fragile.dispatch(2);
+	assert_eq!(*fragile.view(), 2);
+}
+
+#[test]
+fn async_adapters() {
+	use futures::{task::noop_waker, FutureExt, StreamExt};
+	use std::task::{Context, Poll};
+
+	let waker = noop_waker();
+	let mut cx = Context::from_waker(&waker);
+	let state = State::new(1);
+
+	let mut changes = state.changes();
+	assert_eq!(changes.poll_next_unpin(&mut cx), Poll::Pending);
+	*state.update() = 2;
+	*state.update() = 3;
+	assert_eq!(changes.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+	assert_eq!(changes.poll_next_unpin(&mut cx), Poll::Pending);
+
+	let mut ready = state.wait_for(|x| *x == 3);
+	assert_eq!(ready.poll_unpin(&mut cx), Poll::Ready(3));
+	let mut big = state.wait_for(|x| *x > 10);
+	assert_eq!(big.poll_unpin(&mut cx), Poll::Pending);
+	*state.update() = 5;
+	assert_eq!(big.poll_unpin(&mut cx), Poll::Pending);
+	*state.update() = 11;
+	assert_eq!(big.poll_unpin(&mut cx), Poll::Ready(11));
+
+	drop(changes);
+	assert_eq!(state.0.borrow().meta.borrow().subscribers.len(), 2);
+
+	let mut changes = state.changes();
+	*state.update() = 12;
+	drop((state, ready, big));
+	assert_eq!(changes.poll_next_unpin(&mut cx), Poll::Ready(Some(12)));
+	assert_eq!(changes.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn inspector() {
+	inspector::enable();
+
+	let count = State::new(1).inspect("count");
+	let _sub = count.subscribe(|| {});
+	*count.update() = 2;
+	let info = inspector::snapshot().into_iter().find(|x| x.name == "count").unwrap();
+	assert_eq!((info.subscribers, info.updates, info.invocations), (1, 1, 1));
+	assert_eq!(info.value.as_deref(), Some("2"));
+
+	let temporary = State::new(0).named("temporary");
+	assert!(inspector::snapshot().iter().any(|x| x.name == "temporary"));
+	drop(temporary);
+	assert!(inspector::snapshot().iter().all(|x| x.name != "temporary"));
+
+	let runaway = State::new(0).named("runaway");
+	let _loop = runaway.subscribe(crate::enclose!((runaway) move || *runaway.update() += 1));
+	let message = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| *runaway.update() = 1)).unwrap_err();
+	let message = message.downcast_ref::<String>().unwrap();
+	assert!(message.starts_with("too many nested updates of `runaway`, kept being updated by subscriber"));
+	assert!(message.contains("update chain: subscriber"));
+
+	inspector::disable();
+}
+
+#[test]
+fn lens_target_removed() {
+	let state = State::new(vec![1, 2, 3]);
+	let last = state.at(2);
+	let runs = Rc::new(RefCell::new(0));
+	let _sub = last.subscribe(crate::enclose!((runs) move || *runs.borrow_mut() += 1));
+
+	state.update().pop();
+	assert!(last.try_view().is_none());
+	assert_eq!(*runs.borrow(), 0);
+
+	state.update().push(4);
+	assert_eq!(*last.view(), 4);
+	assert_eq!(*runs.borrow(), 1);
+}
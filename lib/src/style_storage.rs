@@ -5,6 +5,7 @@ pub use sugars::hash;
 #[derive(Default)]
 pub struct StyleStorage {
 	map: RefCell<HashMap<css::Style, u64>>,
+	pub(crate) prefixer: RefCell<css::Prefixer>,
 }
 
 // replace the ClassPlaceholder with actual element class
@@ -43,6 +44,7 @@ impl StyleStorage {
 		let class = format!("s{}", id);
 
 		fixup_class_placeholders(&mut style, class.clone());
+		let style = self.prefixer.borrow().prefix_style(&style);
 
 		let dom = crate::dom();
 		let head = dom.head().expect("dom has no head");
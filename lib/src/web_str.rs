@@ -62,6 +62,7 @@ intern_strings! {
 	wrap;
 	autofocus; autoplay;
 	r#async, "async";
+	data_hobo_delegate, "data-hobo-delegate";
 	autocomplete;
 	download;
 	draggable;
@@ -76,4 +77,14 @@ intern_strings! {
 	click; contextmenu; dblclick; mousedown; mouseenter;
 	mouseleave; mousemove; mouseover; mouseout; mouseup;
 	change; keydown; keyup; scroll; resize; blur; focus;
+	pointerdown; pointermove; pointerup; pointercancel; pointerenter; pointerleave;
+	gotpointercapture; lostpointercapture;
+	touchstart; touchmove; touchend; touchcancel;
+	wheel;
+	dragstart; drag; dragend; dragenter; dragover; dragleave; drop;
+	input; beforeinput; compositionstart; compositionupdate; compositionend;
+	copy; cut; paste; select; submit; reset; invalid;
+	animationstart; animationend; animationiteration; animationcancel;
+	transitionrun; transitionstart; transitionend; transitioncancel;
+	popstate; hashchange; visibilitychange; online; offline; storage; beforeunload;
 }
@@ -15,6 +15,63 @@ impl TestDerives {
 			this.flag = true;
 		})
 	}
+
+	#[hobo::trick]
+	fn with_pointer_events() -> Self {
+		Self { element: cmp::div(), flag: false }
+			.on_pointer_move_mut(&this, move |this, event| {
+				this.flag = event.buttons() != 0;
+			})
+			.capture_pointer_on_down()
+	}
+
+	#[hobo::trick]
+	fn with_drag_and_drop() -> Self {
+		Self { element: cmp::div(), flag: false }
+			.on_drag_over(|event| if event.has_format("application/json") { event.accept_drop() })
+			.on_drop_mut(&this, move |this, event| {
+				this.flag = event.value::<bool>("application/json").unwrap_or_default() || !event.file_list().is_empty();
+			})
+	}
+
+	#[hobo::trick]
+	fn with_input_events() -> Self {
+		Self { element: cmp::div(), flag: false }.on_input_mut(&this, move |this, event| {
+			this.flag = !event.is_composing() && event.data().is_some();
+		})
+	}
+
+	#[hobo::trick]
+	fn with_custom_events() -> Self {
+		const TOGGLED: hobo::CustomEvent<bool> = hobo::CustomEvent::new("toggled");
+
+		Self { element: cmp::div(), flag: false }
+			.on_mut(&this, "fullscreenchange", move |this, _: web_sys::Event| {
+				this.flag = false;
+			})
+			.on_custom(&TOGGLED, |flag, _| log::info!("toggled {}", flag))
+	}
+
+	fn with_listener_options() -> Self {
+		Self { element: cmp::div(), flag: false }.on_wheel_with(hobo::ListenerOptions::PASSIVE, |event| log::info!("scrolled {}", event.delta_y()))
+	}
+
+	#[hobo::trick]
+	fn with_global_events() -> Self {
+		Self { element: cmp::div(), flag: false }.on_global_key_down_mut(&this, move |this, event| {
+			this.flag = event.key() == "Escape";
+		})
+	}
+
+	fn with_delegation() -> Self {
+		let rows = hobo::Delegator::document();
+		let element = cmp::div().child(rows.delegate(cmp::div()).on_click(|event| event.stop_propagation()));
+		Self { element, flag: false }
+	}
+
+	fn with_throttle_raf() -> Self {
+		Self { element: cmp::div(), flag: false }.on_scroll(hobo::throttle_raf(|event: web_sys::Event| log::info!("scrolled {:?}", event.target())))
+	}
 }
 
 #[derive(hobo::Element, hobo::Container, hobo::EventTarget)]
@@ -27,3 +84,40 @@ enum SomeEnum {
 struct TestReplaceable {
 	element: Rc<RefCell<cmp::Div>>,
 }
+
+#[derive(hobo::Lenses)]
+struct Model {
+	title: String,
+	settings: Settings,
+}
+
+#[derive(hobo::Lenses)]
+struct Settings {
+	dark: bool,
+}
+
+#[derive(hobo::Lenses)]
+struct Page<T: Clone> where T: PartialEq {
+	items: Vec<T>,
+	cursor: usize,
+}
+
+#[test]
+fn lenses() {
+	let state = hobo::state::State::new(Model { title: "hobo".to_owned(), settings: Settings { dark: false } });
+	let dark = state.settings().dark();
+	let runs = Rc::new(RefCell::new(0));
+	let _sub = dark.subscribe(e!((runs) move || *runs.borrow_mut() += 1));
+
+	state.title().update().push('!');
+	assert_eq!(*runs.borrow(), 0);
+	*dark.update() = true;
+	assert_eq!(*runs.borrow(), 1);
+	assert!(state.view().settings.dark);
+	assert_eq!(*state.title().view(), "hobo!");
+
+	let page = hobo::state::State::new(Page { items: vec![1, 2], cursor: 0 });
+	page.items().update().push(3);
+	*page.cursor().update() = 2;
+	assert_eq!(*page.items().at(page.view().cursor).view(), 3);
+}